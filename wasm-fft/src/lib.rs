@@ -5,15 +5,125 @@
 //! optimized for browser execution.
 //!
 //! ## Supported Sizes
+//! Any power of two from 2 to 256 per dimension, e.g.
 //! - 8x8x8 (512 complex values)
-//! - 16x16x16 (4096 complex values)
 //! - 32x32x32 (32768 complex values)
+//! - 128x128x128 (2097152 complex values)
 
 use wasm_bindgen::prelude::*;
-use rustfft::{FftPlanner, Fft};
+use rustfft::{FftNum, FftPlanner, Fft};
 use num_complex::Complex;
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
 use std::sync::Arc;
 
+/// Smallest cube size accepted by the transforms
+const MIN_SIZE: usize = 2;
+
+/// Largest cube size accepted by the transforms
+const MAX_SIZE: usize = 256;
+
+/// Forward and inverse 1D FFT plans for a single size
+type FftPlans = (Arc<dyn Fft<f32>>, Arc<dyn Fft<f32>>);
+
+/// 1D window coefficients keyed by window and axis length
+type WindowCache = HashMap<(Window, usize), Rc<[f32]>>;
+
+/// Structured error for fallible operations
+///
+/// Converted into a JS `Error` whose `message` is the `Display` text and whose
+/// `code` property holds a stable machine-readable string (see `FftError::code`),
+/// so callers can branch on `error.code` instead of parsing messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FftError {
+    /// Transform size is not a power of two between `MIN_SIZE` and `MAX_SIZE`
+    UnsupportedSize { size: usize },
+    /// An input array has the wrong length
+    LengthMismatch { expected: usize, got: usize },
+    /// An input array has a length that fits no valid shape (`expected` describes the constraint)
+    InvalidLength { got: usize, expected: String },
+    /// The operation is only defined for cubic transforms
+    NonCubic { dims: [usize; 3] },
+    /// A `size`^3 region at `origin` does not fit inside a `parent_size`^3 grid
+    RegionOutOfBounds { origin: [usize; 3], size: usize, parent_size: usize },
+    /// A scalar parameter such as an axis, cutoff or step is out of range
+    InvalidArgument { name: &'static str, reason: String },
+    /// The input data is valid in shape but degenerate for the operation
+    InvalidInput { reason: String },
+    /// An input array is empty
+    EmptyInput,
+    /// An input value is NaN or infinite (reported in strict validation mode)
+    NonFinite { index: usize },
+    /// A buffer for a transform of `size` could not be allocated
+    OutOfMemory { size: usize },
+}
+
+impl FftError {
+    /// Machine-readable error code
+    pub fn code(&self) -> &'static str {
+        match self {
+            FftError::UnsupportedSize { .. } => "UNSUPPORTED_SIZE",
+            FftError::LengthMismatch { .. } => "LENGTH_MISMATCH",
+            FftError::InvalidLength { .. } => "INVALID_LENGTH",
+            FftError::NonCubic { .. } => "NON_CUBIC",
+            FftError::RegionOutOfBounds { .. } => "REGION_OUT_OF_BOUNDS",
+            FftError::InvalidArgument { .. } => "INVALID_ARGUMENT",
+            FftError::InvalidInput { .. } => "INVALID_INPUT",
+            FftError::EmptyInput => "EMPTY_INPUT",
+            FftError::NonFinite { .. } => "NON_FINITE",
+            FftError::OutOfMemory { .. } => "OUT_OF_MEMORY",
+        }
+    }
+}
+
+impl fmt::Display for FftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FftError::UnsupportedSize { size } => write!(
+                f,
+                "Unsupported FFT size: {}. Supported sizes: powers of two from {} to {}",
+                size, MIN_SIZE, MAX_SIZE
+            ),
+            FftError::LengthMismatch { expected, got } => {
+                write!(f, "Invalid input length: expected {}, got {}", expected, got)
+            }
+            FftError::InvalidLength { got, expected } => {
+                write!(f, "Invalid input length: {}. Expected {}", got, expected)
+            }
+            FftError::NonCubic { dims: [nx, ny, nz] } => {
+                write!(f, "Operation requires a cubic transform, got {}x{}x{}", nx, ny, nz)
+            }
+            FftError::RegionOutOfBounds {
+                origin: [x, y, z],
+                size,
+                parent_size,
+            } => write!(
+                f,
+                "Region at ({}, {}, {}) of size {} exceeds parent size {}",
+                x, y, z, size, parent_size
+            ),
+            FftError::InvalidArgument { name, reason } => write!(f, "Invalid {}: {}", name, reason),
+            FftError::InvalidInput { reason } => write!(f, "{}", reason),
+            FftError::EmptyInput => write!(f, "Input must not be empty"),
+            FftError::NonFinite { index } => write!(f, "Non-finite input value at index {}", index),
+            FftError::OutOfMemory { size } => write!(f, "Out of memory for size {}", size),
+        }
+    }
+}
+
+impl std::error::Error for FftError {}
+
+impl From<FftError> for JsValue {
+    fn from(error: FftError) -> JsValue {
+        let js_error = js_sys::Error::new(&error.to_string());
+        // Setting a property on a fresh Error object cannot fail
+        let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("code"), &JsValue::from_str(error.code()));
+        js_error.into()
+    }
+}
+
 /// Initialize panic hook for better error messages in development
 #[wasm_bindgen(start)]
 pub fn init() {
@@ -25,9 +135,7 @@ pub fn init() {
 /// Caches FFT plans for reuse, improving performance for repeated transforms
 #[wasm_bindgen]
 pub struct FFTPlanCache {
-    size_8: Option<(Arc<dyn Fft<f32>>, Arc<dyn Fft<f32>>)>,
-    size_16: Option<(Arc<dyn Fft<f32>>, Arc<dyn Fft<f32>>)>,
-    size_32: Option<(Arc<dyn Fft<f32>>, Arc<dyn Fft<f32>>)>,
+    plans: HashMap<usize, FftPlans>,
     planner: FftPlanner<f32>,
 }
 
@@ -37,45 +145,40 @@ impl FFTPlanCache {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
         FFTPlanCache {
-            size_8: None,
-            size_16: None,
-            size_32: None,
+            plans: HashMap::new(),
             planner: FftPlanner::new(),
         }
     }
 
     /// Get or create forward/inverse FFT plans for a given size
-    fn get_plans(&mut self, size: usize) -> Result<(Arc<dyn Fft<f32>>, Arc<dyn Fft<f32>>), JsValue> {
-        match size {
-            8 => {
-                if self.size_8.is_none() {
-                    let fwd = self.planner.plan_fft_forward(8);
-                    let inv = self.planner.plan_fft_inverse(8);
-                    self.size_8 = Some((fwd, inv));
-                }
-                Ok(self.size_8.as_ref().unwrap().clone())
-            }
-            16 => {
-                if self.size_16.is_none() {
-                    let fwd = self.planner.plan_fft_forward(16);
-                    let inv = self.planner.plan_fft_inverse(16);
-                    self.size_16 = Some((fwd, inv));
-                }
-                Ok(self.size_16.as_ref().unwrap().clone())
-            }
-            32 => {
-                if self.size_32.is_none() {
-                    let fwd = self.planner.plan_fft_forward(32);
-                    let inv = self.planner.plan_fft_inverse(32);
-                    self.size_32 = Some((fwd, inv));
-                }
-                Ok(self.size_32.as_ref().unwrap().clone())
-            }
-            _ => Err(JsValue::from_str(&format!(
-                "Unsupported FFT size: {}. Supported sizes: 8, 16, 32",
-                size
-            ))),
-        }
+    fn get_plans(&mut self, size: usize) -> Result<FftPlans, FftError> {
+        validate_size(size)?;
+
+        let planner = &mut self.planner;
+        let plans = self.plans.entry(size).or_insert_with(|| {
+            let fwd = planner.plan_fft_forward(size);
+            let inv = planner.plan_fft_inverse(size);
+            (fwd, inv)
+        });
+        Ok(plans.clone())
+    }
+
+    /// Estimate the memory held by the cached plans, in bytes
+    ///
+    /// Counts the twiddle factors of each cached forward/inverse pair (as
+    /// `size_info` does) plus their in-place scratch lengths. Plans do not
+    /// retain scratch buffers of their own; transforms allocate them per call.
+    /// Not byte-exact, but proportional to the cached sizes.
+    #[wasm_bindgen(getter)]
+    pub fn estimated_bytes(&self) -> usize {
+        let complex_bytes = std::mem::size_of::<Complex<f32>>();
+        self.plans
+            .iter()
+            .map(|(&size, (fwd, inv))| {
+                let scratch = fwd.get_inplace_scratch_len() + inv.get_inplace_scratch_len();
+                estimated_plan_bytes(size) + scratch * complex_bytes
+            })
+            .sum()
     }
 }
 
@@ -85,13 +188,101 @@ impl Default for FFTPlanCache {
     }
 }
 
+/// Normalization convention selecting where the transforms apply their scale factors
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormMode {
+    /// `forward` is unscaled and `inverse` multiplies by `1 / size^3` (default)
+    Backward = 0,
+    /// `forward` multiplies by `1 / size^3` and `inverse` is unscaled
+    Forward = 1,
+    /// Both directions multiply by `1 / sqrt(size^3)` once, like NumPy's `norm="ortho"`
+    Ortho = 2,
+    /// Every 1D pass of both directions multiplies by `1 / sqrt(size)`
+    OrthonormalPerAxis = 3,
+}
+
+/// Window function applied along each axis before a transform
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Window {
+    /// All ones; leaves data unchanged
+    Rectangular = 0,
+    /// `0.5 - 0.5 cos(2πn / (N-1))`, zero at both ends
+    Hann = 1,
+    /// `0.54 - 0.46 cos(2πn / (N-1))`
+    Hamming = 2,
+    /// `0.42 - 0.5 cos(2πn / (N-1)) + 0.08 cos(4πn / (N-1))`, zero at both ends
+    Blackman = 3,
+}
+
+/// Shape of the transition band used by the `*_smooth` filters
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rolloff {
+    /// Hermite `3t² - 2t³`
+    Smoothstep = 0,
+    /// Raised cosine `(1 - cos(πt)) / 2`, which rings less in the spatial domain
+    RaisedCosine = 1,
+}
+
+impl Rolloff {
+    /// Gain rising from 0 below `edge - width/2` to 1 above `edge + width/2`
+    /// (`width` must be > 0)
+    fn step(self, edge: f32, width: f32, x: f32) -> f32 {
+        match self {
+            Rolloff::Smoothstep => smoothstep(edge - width / 2.0, edge + width / 2.0, x),
+            Rolloff::RaisedCosine => raised_cosine_step(edge, width, x),
+        }
+    }
+}
+
+impl Window {
+    /// Symmetric window coefficients for an axis of length `n`
+    fn coefficients(self, n: usize) -> Vec<f32> {
+        let denom = (n - 1).max(1) as f64;
+        (0..n)
+            .map(|i| {
+                let t = 2.0 * std::f64::consts::PI * i as f64 / denom;
+                let w = match self {
+                    Window::Rectangular => 1.0,
+                    Window::Hann => 0.5 - 0.5 * t.cos(),
+                    Window::Hamming => 0.54 - 0.46 * t.cos(),
+                    Window::Blackman => 0.42 - 0.5 * t.cos() + 0.08 * (2.0 * t).cos(),
+                };
+                // Blackman's ends evaluate to ~1e-17 rather than exactly zero
+                if w.abs() < 1e-12 { 0.0 } else { w as f32 }
+            })
+            .collect()
+    }
+}
+
 /// 3D FFT Transformer
 /// Performs forward and inverse 3D FFT transformations
 #[wasm_bindgen]
 pub struct FFT3D {
     size: usize,
+    /// Length of each axis as `[nx, ny, nz]`; all equal `size` for a cube
+    dims: [usize; 3],
     total_size: usize,
-    plan_cache: FFTPlanCache,
+    /// Plan cache, possibly shared with other transformers via `share_cache_with`
+    plan_cache: Rc<RefCell<FFTPlanCache>>,
+    /// Complex work buffer reused by the `*_into` methods
+    work: Vec<Complex<f32>>,
+    /// Duration of the most recent timed `fft_3d` call in milliseconds
+    last_transform_ms: f64,
+    /// Whether transforms without a progress callback read the clock
+    record_timing: bool,
+    /// Whether `inverse` enforces Hermitian symmetry and returns only real parts
+    real_mode: bool,
+    /// Whether `forward`/`inverse` zero subnormal output values
+    flush_subnormals: bool,
+    /// Normalization convention applied by every transform
+    norm_mode: NormMode,
+    /// Whether input validation rejects NaN and infinite values
+    strict_validation: bool,
+    /// 1D window coefficients keyed by window and axis length
+    window_cache: RefCell<WindowCache>,
 }
 
 #[wasm_bindgen]
@@ -99,38 +290,302 @@ impl FFT3D {
     /// Create a new 3D FFT transformer
     ///
     /// # Arguments
-    /// * `size` - Size of each dimension (8, 16, or 32)
+    /// * `size` - Size of each dimension (a power of two from 2 to 256)
     ///
     /// # Returns
     /// * `Result<FFT3D, JsValue>` - The transformer or an error
     #[wasm_bindgen(constructor)]
     pub fn new(size: usize) -> Result<FFT3D, JsValue> {
-        if size != 8 && size != 16 && size != 32 {
-            return Err(JsValue::from_str(&format!(
-                "Unsupported FFT size: {}. Supported sizes: 8, 16, 32",
-                size
-            )));
+        FFT3D::new_dims(size, size, size)
+    }
+
+    /// Create a 3D FFT transformer for an `nx x ny x nz` box
+    ///
+    /// Data is laid out with X fastest, then Y, then Z. One 1D plan is created
+    /// per distinct axis length. Methods that only make sense for a cube (such as
+    /// `project_axis` or `forward_tiled`) return an error for non-cubic shapes.
+    ///
+    /// # Arguments
+    /// * `nx` - Length of the X axis (a power of two from 2 to 256)
+    /// * `ny` - Length of the Y axis (a power of two from 2 to 256)
+    /// * `nz` - Length of the Z axis (a power of two from 2 to 256)
+    ///
+    /// # Returns
+    /// * `Result<FFT3D, JsValue>` - The transformer or an error
+    #[wasm_bindgen]
+    pub fn new_dims(nx: usize, ny: usize, nz: usize) -> Result<FFT3D, JsValue> {
+        for n in [nx, ny, nz] {
+            validate_size(n)?;
         }
 
         Ok(FFT3D {
-            size,
-            total_size: size * size * size,
-            plan_cache: FFTPlanCache::new(),
+            size: nx,
+            dims: [nx, ny, nz],
+            total_size: nx * ny * nz,
+            plan_cache: Rc::new(RefCell::new(FFTPlanCache::new())),
+            work: Vec::new(),
+            last_transform_ms: 0.0,
+            record_timing: false,
+            real_mode: false,
+            flush_subnormals: false,
+            norm_mode: NormMode::Backward,
+            strict_validation: false,
+            window_cache: RefCell::new(HashMap::new()),
         })
     }
 
-    /// Get the size of each dimension
+    /// Use the same plan cache as another transformer
+    ///
+    /// After this call both transformers plan into and read from a single cache,
+    /// so plans for a size are created once however many instances use it. This
+    /// instance's previous cache is dropped unless another transformer still
+    /// shares it. The cache is only borrowed while plans are looked up, never
+    /// during a transform, so interleaving calls on sharing instances is safe.
+    ///
+    /// # Arguments
+    /// * `other` - Transformer whose cache to share (may have a different size)
+    #[wasm_bindgen]
+    pub fn share_cache_with(&mut self, other: &FFT3D) {
+        self.plan_cache = Rc::clone(&other.plan_cache);
+    }
+
+    /// Change the transform to a cube of a different size
+    ///
+    /// The plan cache is kept, so sizes planned earlier stay cached and switching
+    /// back is cheap. Work and scratch buffers are resized on the next transform
+    /// that uses them, not here. A non-cubic transformer becomes cubic.
+    ///
+    /// # Arguments
+    /// * `size` - New cube dimension (a power of two from 2 to 256)
+    #[wasm_bindgen]
+    pub fn set_size(&mut self, size: usize) -> Result<(), JsValue> {
+        validate_size(size)?;
+        self.size = size;
+        self.dims = [size; 3];
+        self.total_size = size * size * size;
+        Ok(())
+    }
+
+    /// Get the size of each dimension (the X length for a non-cubic transform)
     #[wasm_bindgen(getter)]
     pub fn size(&self) -> usize {
         self.size
     }
 
+    /// Get the length of each axis as `[nx, ny, nz]`
+    #[wasm_bindgen(getter)]
+    pub fn dims(&self) -> Vec<u32> {
+        self.dims.iter().map(|&n| n as u32).collect()
+    }
+
     /// Get the total number of elements
     #[wasm_bindgen(getter)]
     pub fn total_size(&self) -> usize {
         self.total_size
     }
 
+    /// Get the scale applied by `inverse` to the raw inverse transform
+    ///
+    /// With the default `NormMode::Backward` convention `forward` is unscaled and
+    /// `inverse` multiplies by `1 / size^3`. `NormMode::Forward` moves the whole
+    /// factor to `forward` and `NormMode::Ortho` splits it as `1 / sqrt(size^3)`
+    /// per direction. With `NormMode::OrthonormalPerAxis` the scaling happens
+    /// inside each 1D pass, so no further factor is applied.
+    #[wasm_bindgen(getter)]
+    pub fn normalization_factor(&self) -> f32 {
+        match self.norm_mode {
+            NormMode::Backward => 1.0 / (self.total_size as f32),
+            NormMode::Forward | NormMode::OrthonormalPerAxis => 1.0,
+            NormMode::Ortho => 1.0 / (self.total_size as f32).sqrt(),
+        }
+    }
+
+    /// Get the normalization convention
+    #[wasm_bindgen(getter)]
+    pub fn norm_mode(&self) -> NormMode {
+        self.norm_mode
+    }
+
+    /// Set the normalization convention
+    ///
+    /// Every convention keeps `inverse(forward(x)) == x`; they differ in how the
+    /// overall `1 / size^3` is split between the two directions. Analysis methods
+    /// such as `matched_filter` return the same values under every convention,
+    /// while spectra (and quantities derived from them) follow the chosen scaling.
+    ///
+    /// `NormMode::OrthonormalPerAxis` scales each of the three 1D passes by
+    /// `1 / sqrt(size)` in both directions instead of applying `1 / size^3` once
+    /// after the inverse. The results differ from a single `1 / sqrt(size^3)` only
+    /// by rounding, which matters when bit-matching references that scale per pass.
+    /// The mode applies to every method of this transformer.
+    ///
+    /// # Arguments
+    /// * `mode` - Normalization convention
+    #[wasm_bindgen]
+    pub fn set_norm(&mut self, mode: NormMode) {
+        self.norm_mode = mode;
+    }
+
+    /// Check whether real-field mode is enabled
+    #[wasm_bindgen(getter)]
+    pub fn real_mode(&self) -> bool {
+        self.real_mode
+    }
+
+    /// Enable or disable real-field mode
+    ///
+    /// In real-field mode `inverse` first enforces Hermitian symmetry on the input
+    /// spectrum by averaging each coefficient with the conjugate of its mirror,
+    /// `(c[k] + conj(c[-k])) / 2`, and then returns only the `size^3` real parts.
+    /// The symmetrization costs one extra pass over the spectrum.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to guarantee a real output from `inverse`
+    #[wasm_bindgen]
+    pub fn set_real_mode(&mut self, enabled: bool) {
+        self.real_mode = enabled;
+    }
+
+    /// Check whether a spectrum is Hermitian, i.e. the transform of a real field
+    ///
+    /// Tests `c[k] == conj(c[-k])` with indices wrapped on all three axes,
+    /// stopping at the first pair whose real or imaginary parts differ by more
+    /// than `tolerance`. Read-only; use it to confirm a spectrum is safe for
+    /// `inverse_real` or real-field mode.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `tolerance` - Largest allowed absolute difference per component
+    #[wasm_bindgen]
+    pub fn is_hermitian(&self, input_real: &[f32], input_imag: &[f32], tolerance: f32) -> Result<bool, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        Ok((0..self.total_size).all(|i| {
+            let m = self.mirror_index(i);
+            (input_real[i] - input_real[m]).abs() <= tolerance && (input_imag[i] + input_imag[m]).abs() <= tolerance
+        }))
+    }
+
+    /// Check whether subnormal flushing is enabled
+    #[wasm_bindgen(getter)]
+    pub fn flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
+    /// Enable or disable flushing subnormal output values to zero
+    ///
+    /// When enabled, every method that returns a transformed volume (`forward`,
+    /// `inverse` and all their variants, plus the filters that run a transform)
+    /// replaces subnormal components of its output (below about 1.2e-38 in
+    /// magnitude) with zero. WebAssembly always computes with
+    /// subnormals, and x86 CPUs without flush-to-zero take a slow microcode path
+    /// for them, so spectra with many near-zero coefficients can make downstream
+    /// processing much slower. The accuracy cost is negligible.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to zero subnormal output values
+    #[wasm_bindgen]
+    pub fn set_flush_subnormals(&mut self, enabled: bool) {
+        self.flush_subnormals = enabled;
+    }
+
+    /// Check whether strict input validation is enabled
+    #[wasm_bindgen(getter)]
+    pub fn strict_validation(&self) -> bool {
+        self.strict_validation
+    }
+
+    /// Enable or disable strict input validation
+    ///
+    /// When enabled, every method that validates its input arrays also scans
+    /// them for NaN and infinite values and fails with a `NON_FINITE` error
+    /// naming the first offending index, before any transform runs. Off by
+    /// default because the scan costs an extra pass over the input.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to reject non-finite input values
+    #[wasm_bindgen]
+    pub fn set_strict_validation(&mut self, enabled: bool) {
+        self.strict_validation = enabled;
+    }
+
+    /// Get the duration of the most recent timed transform in milliseconds
+    ///
+    /// Only transforms run with timing recording enabled, a progress callback,
+    /// or `forward_timed` are measured; this stays 0 until one has run.
+    #[wasm_bindgen(getter)]
+    pub fn last_transform_ms(&self) -> f64 {
+        self.last_transform_ms
+    }
+
+    /// Check whether every transform records its duration
+    #[wasm_bindgen(getter)]
+    pub fn record_timing(&self) -> bool {
+        self.record_timing
+    }
+
+    /// Enable or disable recording the duration of every transform
+    ///
+    /// Off by default: reading the clock is a `performance.now()` lookup through
+    /// JS on every call, so plain transforms skip it unless asked. Methods that
+    /// report progress or timings always read the clock.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to update `last_transform_ms` after every transform
+    #[wasm_bindgen]
+    pub fn set_record_timing(&mut self, enabled: bool) {
+        self.record_timing = enabled;
+    }
+
+    /// Estimate the memory used by this transformer, in bytes
+    ///
+    /// Sums the plan cache estimate (see `FFTPlanCache::estimated_bytes`), the
+    /// retained work buffer and window coefficients, and the peak temporary
+    /// buffers of one transform: the complex input copy, the transpose buffer and
+    /// the plan scratch. A shared plan cache is counted in full by each sharer.
+    #[wasm_bindgen(getter)]
+    pub fn memory_footprint(&self) -> usize {
+        let complex_bytes = std::mem::size_of::<Complex<f32>>();
+        let cache = self.plan_cache.borrow();
+        let scratch_len = self
+            .dims
+            .iter()
+            .filter_map(|n| cache.plans.get(n))
+            .map(|(fwd, _)| fwd.get_inplace_scratch_len())
+            .max()
+            .unwrap_or(0);
+        let windows: usize = self
+            .window_cache
+            .borrow()
+            .values()
+            .map(|w| w.len() * std::mem::size_of::<f32>())
+            .sum();
+
+        cache.estimated_bytes()
+            + self.work.capacity() * complex_bytes
+            + windows
+            + (2 * self.total_size + scratch_len) * complex_bytes
+    }
+
+    /// Estimate the achieved floating-point throughput of the most recent transform
+    ///
+    /// Uses the usual `5·n·log2(n)` operation count per 1D FFT of length `n`,
+    /// summed over the lines of all three axes (`5·N·log2(N)` for `N` elements),
+    /// divided by the measured time.
+    ///
+    /// # Returns
+    /// * `f64` - Floating-point operations per second, or 0 if no transform has
+    ///   been timed yet
+    #[wasm_bindgen]
+    pub fn last_transform_flops(&self) -> f64 {
+        if self.last_transform_ms <= 0.0 {
+            return 0.0;
+        }
+        let total = self.total_size as f64;
+        let flops = 5.0 * total * total.log2();
+        flops / (self.last_transform_ms / 1000.0)
+    }
+
     /// Perform forward 3D FFT (space -> frequency)
     ///
     /// # Arguments
@@ -144,22 +599,14 @@ impl FFT3D {
         self.validate_input(input_real, input_imag)?;
 
         // Create complex buffer
-        let mut buffer: Vec<Complex<f32>> = input_real
-            .iter()
-            .zip(input_imag.iter())
-            .map(|(&re, &im)| Complex::new(re, im))
-            .collect();
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
 
         // Perform 3D FFT
         self.fft_3d(&mut buffer, true)?;
+        self.flush_output(&mut buffer);
 
         // Convert to interleaved output
-        let output: Vec<f32> = buffer
-            .iter()
-            .flat_map(|c| [c.re, c.im])
-            .collect();
-
-        Ok(output)
+        Ok(self.transform_output(&mut buffer)?)
     }
 
     /// Perform inverse 3D FFT (frequency -> space)
@@ -169,192 +616,7045 @@ impl FFT3D {
     /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^3)
     ///
     /// # Returns
-    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3), or
+    ///   only the real parts (length = size^3) in real-field mode
     #[wasm_bindgen]
     pub fn inverse(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
         self.validate_input(input_real, input_imag)?;
 
         // Create complex buffer
-        let mut buffer: Vec<Complex<f32>> = input_real
-            .iter()
-            .zip(input_imag.iter())
-            .map(|(&re, &im)| Complex::new(re, im))
-            .collect();
-
-        // Perform inverse 3D FFT
-        self.fft_3d(&mut buffer, false)?;
-
-        // Normalize by total size (standard IFFT normalization)
-        let scale = 1.0 / (self.total_size as f32);
-        for c in &mut buffer {
-            c.re *= scale;
-            c.im *= scale;
-        }
-
-        // Convert to interleaved output
-        let output: Vec<f32> = buffer
-            .iter()
-            .flat_map(|c| [c.re, c.im])
-            .collect();
+        let buffer = self.complex_buffer(input_real, input_imag)?;
 
-        Ok(output)
-    }
-
-    /// Validate input arrays
-    fn validate_input(&self, input_real: &[f32], input_imag: &[f32]) -> Result<(), JsValue> {
-        if input_real.len() != self.total_size {
-            return Err(JsValue::from_str(&format!(
-                "Invalid input length: expected {}, got {} (real)",
-                self.total_size,
-                input_real.len()
-            )));
-        }
-        if input_imag.len() != self.total_size {
-            return Err(JsValue::from_str(&format!(
-                "Invalid input length: expected {}, got {} (imag)",
-                self.total_size,
-                input_imag.len()
-            )));
-        }
-        Ok(())
+        self.inverse_buffer(buffer)
     }
 
-    /// Perform 3D FFT by applying 1D FFT along each axis
-    fn fft_3d(&mut self, buffer: &mut [Complex<f32>], forward: bool) -> Result<(), JsValue> {
-        let (fft_forward, fft_inverse) = self.plan_cache.get_plans(self.size)?;
-        let fft = if forward { &fft_forward } else { &fft_inverse };
-
-        let n = self.size;
-
-        // Allocate scratch buffer once
-        let mut scratch = vec![Complex::new(0.0f32, 0.0f32); n];
+    /// Perform inverse 3D FFT and return only the real parts (frequency -> space)
+    ///
+    /// Same transform and normalization as `inverse`, but the imaginary parts are
+    /// dropped, halving the output for spectra known to be Hermitian. Unlike
+    /// real-field mode, the input is not symmetrized first; check it with
+    /// `is_hermitian` if unsure.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^3)
+    /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Real parts of the normalized output (length = size^3)
+    #[wasm_bindgen]
+    pub fn inverse_real_out(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
 
-        // Transform along X-axis
-        for z in 0..n {
-            for y in 0..n {
-                let start = z * n * n + y * n;
-                fft.process_with_scratch(&mut buffer[start..start + n], &mut scratch);
-            }
-        }
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+        self.flush_output(&mut buffer);
 
-        // Transform along Y-axis
-        for z in 0..n {
-            for x in 0..n {
-                // Gather Y-slice
-                let mut slice: Vec<Complex<f32>> = (0..n)
-                    .map(|y| buffer[z * n * n + y * n + x])
-                    .collect();
+        Ok(self.real_parts(&buffer)?)
+    }
 
-                fft.process_with_scratch(&mut slice, &mut scratch);
+    /// Perform forward 3D FFT, reporting progress to a JS callback
+    ///
+    /// `callback` is called with the completed fraction in `[0, 1]` after the X,
+    /// Y and Z passes, so a UI can drive a progress bar. An exception thrown by
+    /// the callback stops further calls and is returned once the transform ends.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    /// * `callback` - Progress function, or `null` to behave like `forward`
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_with_progress(
+        &mut self,
+        input_real: &[f32],
+        input_imag: &[f32],
+        callback: Option<js_sys::Function>,
+    ) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
 
-                // Scatter back
-                for y in 0..n {
-                    buffer[z * n * n + y * n + x] = slice[y];
-                }
-            }
+        let mut callback_error = None;
+        let mut report = |fraction| report_progress(callback.as_ref(), fraction, &mut callback_error);
+        let progress: Option<&mut dyn FnMut(f64)> = if callback.is_some() { Some(&mut report) } else { None };
+        self.fft_3d_with_progress(&mut buffer, true, progress)?;
+        if let Some(error) = callback_error {
+            return Err(error);
         }
 
-        // Transform along Z-axis
-        for y in 0..n {
-            for x in 0..n {
-                // Gather Z-slice
-                let mut slice: Vec<Complex<f32>> = (0..n)
-                    .map(|z| buffer[z * n * n + y * n + x])
-                    .collect();
+        Ok(self.transform_output(&mut buffer)?)
+    }
 
-                fft.process_with_scratch(&mut slice, &mut scratch);
+    /// Perform inverse 3D FFT, reporting progress to a JS callback
+    ///
+    /// Same progress reporting as `forward_with_progress`; the output matches
+    /// `inverse`, including real-field mode.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^3)
+    /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^3)
+    /// * `callback` - Progress function, or `null` to behave like `inverse`
+    ///
+    /// # Returns
+    /// * `Float32Array` - Same layout as `inverse`
+    #[wasm_bindgen]
+    pub fn inverse_with_progress(
+        &mut self,
+        input_real: &[f32],
+        input_imag: &[f32],
+        callback: Option<js_sys::Function>,
+    ) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let buffer = self.complex_buffer(input_real, input_imag)?;
 
-                // Scatter back
-                for z in 0..n {
-                    buffer[z * n * n + y * n + x] = slice[z];
-                }
-            }
+        let mut callback_error = None;
+        let mut report = |fraction| report_progress(callback.as_ref(), fraction, &mut callback_error);
+        let progress: Option<&mut dyn FnMut(f64)> = if callback.is_some() { Some(&mut report) } else { None };
+        let output = self.inverse_buffer_with_progress(buffer, progress)?;
+        match callback_error {
+            Some(error) => Err(error),
+            None => Ok(output),
         }
-
-        Ok(())
     }
-}
 
-/// Calculate total energy using Parseval's theorem
-/// E = sum(|coefficient|^2)
-///
-/// # Arguments
-/// * `coefficients_real` - Real parts of FFT coefficients
-/// * `coefficients_imag` - Imaginary parts of FFT coefficients
-///
-/// # Returns
-/// * `f32` - Total energy
-#[wasm_bindgen]
-pub fn calculate_energy(coefficients_real: &[f32], coefficients_imag: &[f32]) -> Result<f32, JsValue> {
-    if coefficients_real.len() != coefficients_imag.len() {
-        return Err(JsValue::from_str("Real and imaginary arrays must have same length"));
+    /// Perform 1D FFTs along a single axis (space -> frequency along that axis)
+    ///
+    /// Useful when one axis has a different meaning, such as time stored along
+    /// Z. The output is unscaled, whatever the normalization mode.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    /// * `axis` - 0 (X), 1 (Y) or 2 (Z)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_axis(&mut self, input_real: &[f32], input_imag: &[f32], axis: usize) -> Result<Vec<f32>, JsValue> {
+        validate_axis(axis)?;
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_axis(&mut buffer, axis, true)?;
+        Ok(self.transform_output(&mut buffer)?)
     }
 
-    let energy: f32 = coefficients_real
-        .iter()
+    /// Invert `forward_axis`, scaling by `1 / n` for the axis length `n`
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    /// * `axis` - 0 (X), 1 (Y) or 2 (Z)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn inverse_axis(&mut self, input_real: &[f32], input_imag: &[f32], axis: usize) -> Result<Vec<f32>, JsValue> {
+        validate_axis(axis)?;
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_axis(&mut buffer, axis, false)?;
+        let scale = 1.0 / self.dims[axis] as f32;
+        buffer.iter_mut().for_each(|c| *c *= scale);
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Perform forward 3D FFT on interleaved input (space -> frequency)
+    ///
+    /// Takes the same `[re, im, re, im, ...]` layout that `forward` returns, so
+    /// callers holding interleaved data need not split it into two arrays.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved real/imag input (length must be 2 * size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_interleaved(&mut self, data: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_interleaved(data)?;
+
+        let mut buffer = self.deinterleave(data)?;
+        self.fft_3d(&mut buffer, true)?;
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Perform forward 3D FFT on a batch of independent volumes
+    ///
+    /// The volumes are concatenated, each in the interleaved layout of
+    /// `forward_interleaved`. One call crosses the JS/WASM boundary once, and
+    /// each volume is transformed through the reused work buffer, so memory
+    /// beyond the output stays at one volume regardless of `count`.
+    ///
+    /// # Arguments
+    /// * `data` - Concatenated interleaved volumes (length must be count * 2 * size^3)
+    /// * `count` - Number of volumes
+    ///
+    /// # Returns
+    /// * `Float32Array` - Concatenated interleaved spectra (same length as `data`)
+    #[wasm_bindgen]
+    pub fn forward_batch(&mut self, data: &[f32], count: usize) -> Result<Vec<f32>, JsValue> {
+        let volume_len = 2 * self.total_size;
+        let expected = count.checked_mul(volume_len).ok_or_else(|| FftError::InvalidLength {
+            got: data.len(),
+            expected: format!("{} volumes of {} values", count, volume_len),
+        })?;
+        if data.len() != expected {
+            return Err(FftError::LengthMismatch {
+                expected,
+                got: data.len(),
+            }
+            .into());
+        }
+        self.validate_finite(data)?;
+
+        // One volume at a time through the work buffer, straight into the output
+        let mut output = try_alloc(expected, 0.0f32, self.size)?;
+        for (volume, out) in data.chunks_exact(volume_len).zip(output.chunks_exact_mut(volume_len)) {
+            self.forward_to_slice(Some(volume), out)?;
+        }
+        Ok(output)
+    }
+
+    /// Perform inverse 3D FFT on interleaved input (frequency -> space)
+    ///
+    /// Accepts the output of `forward` or `forward_interleaved` directly and
+    /// otherwise behaves exactly like `inverse`, including real-field mode.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved real/imag spectrum (length must be 2 * size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3), or
+    ///   only the real parts (length = size^3) in real-field mode
+    #[wasm_bindgen]
+    pub fn inverse_interleaved(&mut self, data: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_interleaved(data)?;
+
+        let buffer = self.deinterleave(data)?;
+        self.inverse_buffer(buffer)
+    }
+
+    /// Shared tail of `inverse` and `inverse_interleaved`
+    fn inverse_buffer(&mut self, buffer: Vec<Complex<f32>>) -> Result<Vec<f32>, JsValue> {
+        self.inverse_buffer_with_progress(buffer, None)
+    }
+
+    /// `inverse_buffer`, reporting per-pass progress to `progress` if given
+    fn inverse_buffer_with_progress(
+        &mut self,
+        mut buffer: Vec<Complex<f32>>,
+        progress: Option<&mut dyn FnMut(f64)>,
+    ) -> Result<Vec<f32>, JsValue> {
+        if self.real_mode {
+            self.enforce_hermitian(&mut buffer);
+        }
+
+        // Perform inverse 3D FFT
+        self.fft_3d_with_progress(&mut buffer, false, progress)?;
+
+        // Normalize by total size (standard IFFT normalization)
+        self.normalize_inverse(&mut buffer);
+
+        if self.real_mode {
+            self.flush_output(&mut buffer);
+            return Ok(self.real_parts(&buffer)?);
+        }
+
+        // Convert to interleaved output
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Perform forward 3D FFT of a real field, returning the non-redundant half-spectrum
+    ///
+    /// The spectrum of a real field is Hermitian, `c[-k] = conj(c[k])`, so only
+    /// X frequencies `0..=size/2` are kept. The output holds
+    /// `size * size * (size/2 + 1)` complex values, interleaved, with the halved
+    /// X index fastest: coefficient `(kx, ky, kz)` is at complex index
+    /// `(kz * size + ky) * (size/2 + 1) + kx`. Y and Z use the usual FFT order.
+    /// `rustfft` has no real-to-complex plans, so the transform itself still runs
+    /// as a complex FFT; only the output memory is halved.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real field (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved half-spectrum (length = 2 * size^2 * (size/2 + 1))
+    #[wasm_bindgen]
+    pub fn forward_real(&mut self, input_real: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_real(input_real)?;
+
+        let mut buffer = try_alloc(self.total_size, Complex::new(0.0f32, 0.0), self.size)?;
+        for (c, &re) in buffer.iter_mut().zip(input_real) {
+            c.re = re;
+        }
+        self.fft_3d(&mut buffer, true)?;
+        self.flush_output(&mut buffer);
+
+        let nx = self.dims[0];
+        let half = nx / 2 + 1;
+        let mut output = try_alloc(2 * half * (self.total_size / nx), 0.0f32, self.size)?;
+        for (out_row, row) in output.chunks_exact_mut(2 * half).zip(buffer.chunks_exact(nx)) {
+            for (out, c) in out_row.chunks_exact_mut(2).zip(row.iter()) {
+                out[0] = c.re;
+                out[1] = c.im;
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Reconstruct a real field from a half-spectrum produced by `forward_real`
+    ///
+    /// The missing X frequencies are filled in from the Hermitian mirror before the
+    /// inverse transform, and the normalized real parts are returned.
+    ///
+    /// # Arguments
+    /// * `half_spectrum` - Interleaved half-spectrum (length must be 2 * size^2 * (size/2 + 1))
+    ///
+    /// # Returns
+    /// * `Float32Array` - Real spatial field (length = size^3)
+    #[wasm_bindgen]
+    pub fn inverse_real(&mut self, half_spectrum: &[f32]) -> Result<Vec<f32>, JsValue> {
+        let [nx, ny, nz] = self.dims;
+        let half = nx / 2 + 1;
+        let expected = 2 * half * ny * nz;
+        if half_spectrum.len() != expected {
+            return Err(FftError::LengthMismatch {
+                expected,
+                got: half_spectrum.len(),
+            }
+            .into());
+        }
+
+        let coefficient = |x: usize, y: usize, z: usize| {
+            let h = 2 * ((z * ny + y) * half + x);
+            Complex::new(half_spectrum[h], half_spectrum[h + 1])
+        };
+
+        let mut buffer = try_alloc(self.total_size, Complex::new(0.0f32, 0.0), self.size)?;
+        for (i, c) in buffer.iter_mut().enumerate() {
+            let (x, y, z) = (i % nx, (i / nx) % ny, i / (nx * ny));
+            *c = if x < half {
+                coefficient(x, y, z)
+            } else {
+                coefficient(nx - x, (ny - y) % ny, (nz - z) % nz).conj()
+            };
+        }
+
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+        self.flush_output(&mut buffer);
+        Ok(self.real_parts(&buffer)?)
+    }
+
+    /// Perform inverse 3D FFT of a purely real spectrum (frequency -> space)
+    ///
+    /// The imaginary channel is treated as all-zero, so callers need not build it.
+    /// Only the real parts of the spatial result are returned; they are the whole
+    /// answer when the spectrum is even-symmetric (`c[k] == c[-k]`).
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Real spatial field (length = size^3)
+    #[wasm_bindgen]
+    pub fn inverse_real_spectrum(&mut self, input_real: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_real(input_real)?;
+
+        let mut buffer = self.real_buffer(input_real)?;
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+        self.flush_output(&mut buffer);
+
+        Ok(self.real_parts(&buffer)?)
+    }
+
+    /// Perform inverse 3D FFT and map the real part for display
+    ///
+    /// Fuses the inverse transform, taking the real part and the affine mapping
+    /// `value * scale + bias` into one call.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^3)
+    /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^3)
+    /// * `scale` - Multiplier applied to each real value
+    /// * `bias` - Offset added after scaling
+    ///
+    /// # Returns
+    /// * `Float32Array` - Mapped real field (length = size^3)
+    #[wasm_bindgen]
+    pub fn inverse_mapped(&mut self, input_real: &[f32], input_imag: &[f32], scale: f32, bias: f32) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, false)?;
+        self.flush_output(&mut buffer);
+
+        // Fold the inverse normalization into the display scale
+        let factor = scale * self.normalization_factor();
+        let mut output = self.real_parts(&buffer)?;
+        output.iter_mut().for_each(|v| *v = *v * factor + bias);
+        Ok(output)
+    }
+
+    /// Perform forward 3D FFT into a caller-provided buffer (space -> frequency)
+    ///
+    /// Reuses an internal complex work buffer, so repeated calls do not allocate
+    /// the complex buffer or the output array.
+    ///
+    /// # Arguments
+    /// * `input` - Interleaved real/imag input (length must be 2 * size^3)
+    /// * `output` - Destination for the interleaved spectrum (length must be 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_into(&mut self, input: &[f32], output: &mut [f32]) -> Result<(), JsValue> {
+        self.validate_interleaved(input)?;
+        self.validate_interleaved(output)?;
+        self.validate_finite(input)?;
+        Ok(self.forward_to_slice(Some(input), output)?)
+    }
+
+    /// Perform forward 3D FFT of interleaved data, overwriting it with the spectrum
+    ///
+    /// Like `forward_into` with the same array as input and output: the complex
+    /// conversion goes through the internal work buffer, so repeated calls do
+    /// not allocate. On error `data` is left unchanged.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved real/imag input, replaced by the interleaved
+    ///   spectrum (length must be 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_in_place(&mut self, data: &mut [f32]) -> Result<(), JsValue> {
+        self.validate_interleaved(data)?;
+        self.validate_finite(data)?;
+        Ok(self.forward_to_slice(None, data)?)
+    }
+
+    /// Perform inverse 3D FFT into a caller-provided buffer (frequency -> space)
+    ///
+    /// Reuses an internal complex work buffer, so repeated calls do not allocate.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^3)
+    /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^3)
+    /// * `output` - Destination for the normalized interleaved output (length must be 2 * size^3)
+    #[wasm_bindgen]
+    pub fn inverse_into(&mut self, input_real: &[f32], input_imag: &[f32], output: &mut [f32]) -> Result<(), JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        self.validate_interleaved(output)?;
+
+        let mut buffer = self.take_work(input_real.len())?;
+        buffer.extend(
+            input_real
+                .iter()
+                .zip(input_imag.iter())
+                .map(|(&re, &im)| Complex::new(re, im)),
+        );
+
+        let result = self.fft_3d(&mut buffer, false);
+        if result.is_ok() {
+            self.normalize_inverse(&mut buffer);
+            self.flush_output(&mut buffer);
+            write_interleaved(&buffer, output);
+        }
+
+        self.work = buffer;
+        result.map_err(Into::into)
+    }
+
+    /// Perform inverse 3D FFT with per-coefficient weights (frequency -> space)
+    ///
+    /// Each coefficient is multiplied by its weight before the inverse transform,
+    /// which allows simple Wiener-like denoising with caller-supplied weights.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^3)
+    /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^3)
+    /// * `weights` - Weight per coefficient (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3), or
+    ///   only the real parts (length = size^3) in real-field mode
+    #[wasm_bindgen]
+    pub fn inverse_weighted(
+        &mut self,
+        input_real: &[f32],
+        input_imag: &[f32],
+        weights: &[f32],
+    ) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        if weights.len() != self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: self.total_size,
+                got: weights.len(),
+            }
+            .into());
+        }
+
+        // Create weighted complex buffer
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        for (c, &w) in buffer.iter_mut().zip(weights.iter()) {
+            *c *= w;
+        }
+
+        self.inverse_buffer(buffer)
+    }
+
+    /// Shift a real field by a fractional number of voxels
+    ///
+    /// Applies the linear phase ramp `exp(-i2π(f·d))` in the frequency domain, which
+    /// is exact sinc interpolation for band-limited fields. The shift wraps around
+    /// the boundaries (the field is treated as periodic), so content leaving one
+    /// face re-enters on the opposite face. The Nyquist bin uses the real part of
+    /// the ramp to keep the spectrum Hermitian and the output real.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real field (length must be size^3)
+    /// * `dx` - Shift along X in voxels
+    /// * `dy` - Shift along Y in voxels
+    /// * `dz` - Shift along Z in voxels
+    ///
+    /// # Returns
+    /// * `Float32Array` - Shifted real field (length = size^3)
+    #[wasm_bindgen]
+    pub fn shift_subvoxel(&mut self, input_real: &[f32], dx: f32, dy: f32, dz: f32) -> Result<Vec<f32>, JsValue> {
+        self.require_cubic()?;
+        self.validate_real(input_real)?;
+
+        let mut buffer = self.real_buffer(input_real)?;
+        self.fft_3d(&mut buffer, true)?;
+
+        // Per-axis phase ramps; the 3D ramp is their product
+        let n = self.size;
+        let ramp = |d: f32| -> Vec<Complex<f32>> {
+            (0..n)
+                .map(|k| {
+                    if 2 * k == n {
+                        Complex::new((std::f32::consts::PI * d).cos(), 0.0)
+                    } else {
+                        let angle = -2.0 * std::f32::consts::PI * signed_frequency(k, n) * d / n as f32;
+                        Complex::from_polar(1.0, angle)
+                    }
+                })
+                .collect()
+        };
+        let (ramp_x, ramp_y, ramp_z) = (ramp(dx), ramp(dy), ramp(dz));
+
+        for (row_index, row) in buffer.chunks_exact_mut(n).enumerate() {
+            let yz = ramp_z[row_index / n] * ramp_y[row_index % n];
+            for (c, &rx) in row.iter_mut().zip(ramp_x.iter()) {
+                *c *= yz * rx;
+            }
+        }
+
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+
+        // A real input must stay real after a Hermitian phase ramp
+        let peak = buffer.iter().fold(0.0f32, |m, c| m.max(c.re.abs()));
+        let max_imag = buffer.iter().fold(0.0f32, |m, c| m.max(c.im.abs()));
+        if max_imag > 1e-3 * peak.max(1e-6) {
+            return Err(FftError::InvalidInput {
+                reason: format!("Shifted field is not real: max imaginary part {} (peak {})", max_imag, peak),
+            }
+            .into());
+        }
+
+        Ok(self.real_parts(&buffer)?)
+    }
+
+    /// Blur a field with a Gaussian kernel in the frequency domain
+    ///
+    /// Multiplies each coefficient by `exp(-2π²σ²|f|²)`, where `f` is the signed
+    /// frequency in cycles per voxel, so the cost does not grow with `sigma`.
+    /// The kernel wraps around the boundaries (the field is treated as periodic).
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the field (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `sigma` - Standard deviation in voxels; 0 returns the input unchanged
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag blurred field (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn gaussian_blur(&mut self, input_real: &[f32], input_imag: &[f32], sigma: f32) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        if sigma.is_nan() || sigma < 0.0 {
+            return Err(FftError::InvalidArgument {
+                name: "sigma",
+                reason: format!("{}. Expected a non-negative value", sigma),
+            }
+            .into());
+        }
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        if sigma == 0.0 {
+            return Ok(self.transform_output(&mut buffer)?);
+        }
+
+        self.fft_3d(&mut buffer, true)?;
+
+        // The Gaussian is separable, so the 3D gain is a product of axis gains
+        let [nx, ny, nz] = self.dims;
+        let gain = |n: usize| -> Vec<f32> {
+            (0..n)
+                .map(|k| {
+                    let f = signed_frequency(k, n) / n as f32;
+                    (-2.0 * std::f32::consts::PI.powi(2) * sigma * sigma * f * f).exp()
+                })
+                .collect()
+        };
+        let (gain_x, gain_y, gain_z) = (gain(nx), gain(ny), gain(nz));
+
+        for (row_index, row) in buffer.chunks_exact_mut(nx).enumerate() {
+            let yz = gain_z[row_index / ny] * gain_y[row_index % ny];
+            for (c, &gx) in row.iter_mut().zip(gain_x.iter()) {
+                *c *= yz * gx;
+            }
+        }
+
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Differentiate a field along one axis in the frequency domain
+    ///
+    /// Multiplies each coefficient by `i·2π·k/n`, where `k` is the signed
+    /// frequency index along `axis` and `n` that axis length, giving the
+    /// derivative per voxel of the periodic band-limited interpolant. The Nyquist
+    /// bin has no well-defined sign, so its derivative is zeroed; this keeps the
+    /// derivative of a real field real.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the field (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `axis` - 0 (X), 1 (Y) or 2 (Z)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag derivative (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn gradient(&mut self, input_real: &[f32], input_imag: &[f32], axis: usize) -> Result<Vec<f32>, JsValue> {
+        validate_axis(axis)?;
+        self.validate_input(input_real, input_imag)?;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+
+        let [nx, ny, _] = self.dims;
+        let n = self.dims[axis];
+        let stride = [1, nx, nx * ny][axis];
+        for (i, c) in buffer.iter_mut().enumerate() {
+            let k = (i / stride) % n;
+            *c = if 2 * k == n {
+                Complex::new(0.0, 0.0)
+            } else {
+                *c * Complex::new(0.0, 2.0 * std::f32::consts::PI * signed_frequency(k, n) / n as f32)
+            };
+        }
+
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Apply the 3D Laplacian in the frequency domain
+    ///
+    /// Multiplies each coefficient by `-(2π)²·(fx² + fy² + fz²)`, where
+    /// `f = k/n` is the signed frequency in cycles per voxel along each axis.
+    /// Unlike `gradient`, the Nyquist bin needs no special handling because the
+    /// multiplier is real and even. The field is treated as periodic.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the field (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag Laplacian (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn laplacian(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+
+        // Per-axis -(2πf)² terms; the multiplier is their sum
+        let [nx, ny, nz] = self.dims;
+        let eigen = |n: usize| -> Vec<f32> {
+            (0..n)
+                .map(|k| {
+                    let w = 2.0 * std::f32::consts::PI * signed_frequency(k, n) / n as f32;
+                    -w * w
+                })
+                .collect()
+        };
+        let (ex, ey, ez) = (eigen(nx), eigen(ny), eigen(nz));
+
+        for (row_index, row) in buffer.chunks_exact_mut(nx).enumerate() {
+            let yz = ez[row_index / ny] + ey[row_index % ny];
+            for (c, &e) in row.iter_mut().zip(ex.iter()) {
+                *c *= yz + e;
+            }
+        }
+
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Resample a field to a different cube size by Fourier interpolation
+    ///
+    /// The spectrum is computed at the current size and each coefficient with
+    /// signed frequency `k` moves to bin `k mod new_size`, so positive
+    /// frequencies stay at the low end of each axis and negative frequencies at
+    /// the high end. Upsampling zero-pads the new high frequencies and splits the
+    /// old Nyquist bin evenly between `+size/2` and `-size/2`; downsampling drops
+    /// frequencies beyond the new Nyquist and folds `±new_size/2` into its bin.
+    /// Coefficients are scaled by `new_size^3 / size^3` so sample values keep
+    /// their amplitude, whatever the normalization mode.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the field (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `new_size` - Target cube size (a supported size)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag resampled field (length = 2 * new_size^3)
+    #[wasm_bindgen]
+    pub fn resample(&mut self, input_real: &[f32], input_imag: &[f32], new_size: usize) -> Result<Vec<f32>, JsValue> {
+        self.require_cubic()?;
+        self.validate_input(input_real, input_imag)?;
+
+        // Transformer for the target grid, sharing plans and conventions
+        let mut target = FFT3D::new(new_size)?;
+        target.share_cache_with(self);
+        target.norm_mode = self.norm_mode;
+        target.flush_subnormals = self.flush_subnormals;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+
+        let (n, m) = (self.size, new_size);
+        let scale = (m * m * m) as f32 / self.total_size as f32 * target.forward_scale() / self.forward_scale();
+        let axis_map = resample_axis_map(n, m);
+
+        let mut resampled = try_alloc(target.total_size, Complex::new(0.0f32, 0.0), new_size)?;
+        for &(oz, nz, wz) in &axis_map {
+            for &(oy, ny, wy) in &axis_map {
+                let (old_row, new_row) = ((oz * n + oy) * n, (nz * m + ny) * m);
+                let w_yz = wz * wy * scale;
+                for &(ox, nx, wx) in &axis_map {
+                    resampled[new_row + nx] += buffer[old_row + ox] * (w_yz * wx);
+                }
+            }
+        }
+
+        target.fft_3d(&mut resampled, false)?;
+        target.normalize_inverse(&mut resampled);
+        Ok(target.transform_output(&mut resampled)?)
+    }
+
+    /// Project a real field along one axis (line integral)
+    ///
+    /// Computed by direct summation; by the central-slice theorem this equals the
+    /// inverse 2D transform of the spectrum slice through the origin perpendicular
+    /// to `axis`, but summing directly is cheaper and exact.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real field (length must be size^3)
+    /// * `axis` - Axis to sum along (0 = X, 1 = Y, 2 = Z)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Projection of length size^2, indexed by the two remaining
+    ///   axes with the slower one first (`[z][y]`, `[z][x]` or `[y][x]`)
+    #[wasm_bindgen]
+    pub fn project_axis(&self, input_real: &[f32], axis: usize) -> Result<Vec<f32>, JsValue> {
+        self.require_cubic()?;
+        self.validate_real(input_real)?;
+        validate_axis(axis)?;
+
+        let n = self.size;
+        let mut projection = vec![0.0f32; n * n];
+        for z in 0..n {
+            for y in 0..n {
+                for x in 0..n {
+                    let value = input_real[z * n * n + y * n + x];
+                    let target = match axis {
+                        0 => z * n + y,
+                        1 => z * n + x,
+                        _ => y * n + x,
+                    };
+                    projection[target] += value;
+                }
+            }
+        }
+
+        Ok(projection)
+    }
+
+    /// Perform forward 3D FFT and return a single frequency plane
+    ///
+    /// Only the `size^2` coefficients with frequency index `index` along `axis` are
+    /// returned, so a slice browser does not have to transfer the whole spectrum.
+    /// The plane is indexed by the two remaining axes with the slower one first,
+    /// matching `project_axis`.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    /// * `axis` - Axis the slice is taken across (0 = X, 1 = Y, 2 = Z)
+    /// * `index` - Frequency index along `axis` (must be < size)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag plane (length = 2 * size^2)
+    #[wasm_bindgen]
+    pub fn forward_then_slice(
+        &mut self,
+        input_real: &[f32],
+        input_imag: &[f32],
+        axis: usize,
+        index: usize,
+    ) -> Result<Vec<f32>, JsValue> {
+        self.require_cubic()?;
+        self.validate_input(input_real, input_imag)?;
+        validate_axis(axis)?;
+        if index >= self.size {
+            return Err(FftError::InvalidArgument {
+                name: "slice index",
+                reason: format!("{}. Expected < {}", index, self.size),
+            }
+            .into());
+        }
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+        self.flush_output(&mut buffer);
+
+        let n = self.size;
+        let (outer_stride, inner_stride) = match axis {
+            0 => (n * n, n),
+            1 => (n * n, 1),
+            _ => (n, 1),
+        };
+        let offset = index * [1, n, n * n][axis];
+
+        let mut slice = Vec::with_capacity(2 * n * n);
+        for outer in 0..n {
+            for inner in 0..n {
+                let c = buffer[offset + outer * outer_stride + inner * inner_stride];
+                slice.push(c.re);
+                slice.push(c.im);
+            }
+        }
+
+        Ok(slice)
+    }
+
+    /// Perform forward 3D FFT on a volume supplied as tiles
+    ///
+    /// The volume is split into `tiles_per_axis^3` cubic sub-blocks. Tiles are
+    /// concatenated with X tile index fastest, then Y, then Z, and each tile holds
+    /// interleaved complex values in the usual x-fastest order. The tiles are
+    /// reassembled into the full linear layout and transformed, so the result must
+    /// match `forward` on the monolithic volume.
+    ///
+    /// # Arguments
+    /// * `tiles` - Concatenated interleaved tiles (length must be 2 * size^3)
+    /// * `tiles_per_axis` - Number of tiles along each axis (must divide size)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_tiled(&mut self, tiles: &[f32], tiles_per_axis: usize) -> Result<Vec<f32>, JsValue> {
+        self.require_cubic()?;
+        if tiles_per_axis == 0 || !self.size.is_multiple_of(tiles_per_axis) {
+            return Err(FftError::InvalidArgument {
+                name: "tile count",
+                reason: format!("{} tiles per axis does not divide size {}", tiles_per_axis, self.size),
+            }
+            .into());
+        }
+        if tiles.len() != 2 * self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: 2 * self.total_size,
+                got: tiles.len(),
+            }
+            .into());
+        }
+
+        let n = self.size;
+        let t = n / tiles_per_axis;
+        let mut buffer = try_alloc(self.total_size, Complex::new(0.0f32, 0.0f32), n)?;
+        for (tile_index, tile) in tiles.chunks_exact(2 * t * t * t).enumerate() {
+            let tx = tile_index % tiles_per_axis;
+            let ty = (tile_index / tiles_per_axis) % tiles_per_axis;
+            let tz = tile_index / (tiles_per_axis * tiles_per_axis);
+            for (local, c) in tile.chunks_exact(2).enumerate() {
+                let x = tx * t + local % t;
+                let y = ty * t + (local / t) % t;
+                let z = tz * t + local / (t * t);
+                buffer[z * n * n + y * n + x] = Complex::new(c[0], c[1]);
+            }
+        }
+
+        self.fft_3d(&mut buffer, true)?;
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Multiply a field by a separable 3D window, in place
+    ///
+    /// Each sample at `(x, y, z)` is scaled by `w(x) * w(y) * w(z)`, reducing
+    /// spectral leakage from non-periodic data. The 1D coefficients are cached
+    /// per window and axis length, so repeated calls do not recompute them.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the field (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `window` - Window function to apply along every axis
+    #[wasm_bindgen]
+    pub fn apply_window(&self, input_real: &mut [f32], input_imag: &mut [f32], window: Window) -> Result<(), JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let [nx, ny, nz] = self.dims;
+        let [wx, wy, wz] = [nx, ny, nz].map(|n| self.window_coefficients(window, n));
+
+        for (i, (re, im)) in input_real.iter_mut().zip(input_imag.iter_mut()).enumerate() {
+            let w = wx[i % nx] * wy[(i / nx) % ny] * wz[i / (nx * ny)];
+            *re *= w;
+            *im *= w;
+        }
+        Ok(())
+    }
+
+    /// Multiply an interleaved field by a window along selected axes, in place
+    ///
+    /// Like `apply_window`, but only the listed axes are windowed; the others
+    /// keep a weight of 1, so data that is periodic along them stays untouched.
+    /// Listing an axis more than once applies its window once.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved real/imag field (length must be 2 * size^3)
+    /// * `window` - Window function to apply along each listed axis
+    /// * `axes` - Axes to window: 0 (X), 1 (Y) and/or 2 (Z)
+    #[wasm_bindgen]
+    pub fn apply_window_axes(&self, data: &mut [f32], window: Window, axes: &[usize]) -> Result<(), JsValue> {
+        self.validate_interleaved(data)?;
+        let mut windowed = [false; 3];
+        for &axis in axes {
+            validate_axis(axis)?;
+            windowed[axis] = true;
+        }
+        let [nx, ny, _] = self.dims;
+        let [wx, wy, wz] = [0, 1, 2].map(|axis| {
+            let n = self.dims[axis];
+            let axis_window = if windowed[axis] { window } else { Window::Rectangular };
+            self.window_coefficients(axis_window, n)
+        });
+
+        for (i, c) in data.chunks_exact_mut(2).enumerate() {
+            let w = wx[i % nx] * wy[(i / nx) % ny] * wz[i / (nx * ny)];
+            c[0] *= w;
+            c[1] *= w;
+        }
+        Ok(())
+    }
+
+    /// Keep only the largest-magnitude coefficients, zeroing the rest in place
+    ///
+    /// Coefficients are ranked by energy `re² + im²` and the top
+    /// `ceil(keep_fraction * size^3)` are retained. Every coefficient whose energy
+    /// equals the threshold is kept, so ties can retain a few more.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `keep_fraction` - Fraction to keep; `>= 1` is a no-op and `<= 0` zeros everything
+    ///
+    /// # Returns
+    /// * Number of retained coefficients
+    #[wasm_bindgen]
+    pub fn threshold_coefficients(&self, input_real: &mut [f32], input_imag: &mut [f32], keep_fraction: f32) -> Result<usize, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        if keep_fraction.is_nan() {
+            return Err(FftError::InvalidArgument {
+                name: "keep fraction",
+                reason: "NaN".to_string(),
+            }
+            .into());
+        }
+        if keep_fraction >= 1.0 {
+            return Ok(self.total_size);
+        }
+        if keep_fraction <= 0.0 {
+            input_real.fill(0.0);
+            input_imag.fill(0.0);
+            return Ok(0);
+        }
+
+        let energy = |re: f32, im: f32| re * re + im * im;
+        let keep = ((keep_fraction as f64 * self.total_size as f64).ceil() as usize).clamp(1, self.total_size);
+        let mut energies: Vec<f32> = input_real
+            .iter()
+            .zip(input_imag.iter())
+            .map(|(&re, &im)| energy(re, im))
+            .collect();
+        let (_, &mut threshold, _) = energies.select_nth_unstable_by(self.total_size - keep, f32::total_cmp);
+
+        let mut retained = 0;
+        for (re, im) in input_real.iter_mut().zip(input_imag.iter_mut()) {
+            if energy(*re, *im) >= threshold {
+                retained += 1;
+            } else {
+                *re = 0.0;
+                *im = 0.0;
+            }
+        }
+        Ok(retained)
+    }
+
+    /// Shrink every coefficient's magnitude toward zero by `lambda`, in place
+    ///
+    /// A coefficient of magnitude `m > lambda` is scaled by `(m - lambda) / m`,
+    /// which keeps its phase; all others (including zero coefficients) become 0.
+    /// Unlike `threshold_coefficients` the gain is continuous in `m`, avoiding the
+    /// artifacts of hard thresholding.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `lambda` - Shrinkage amount (must be >= 0)
+    #[wasm_bindgen]
+    pub fn soft_threshold(&self, input_real: &mut [f32], input_imag: &mut [f32], lambda: f32) -> Result<(), JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        if lambda.is_nan() || lambda < 0.0 {
+            return Err(FftError::InvalidArgument {
+                name: "lambda",
+                reason: format!("{}. Expected >= 0", lambda),
+            }
+            .into());
+        }
+
+        for (re, im) in input_real.iter_mut().zip(input_imag.iter_mut()) {
+            let m = re.hypot(*im);
+            let gain = if m > lambda { (m - lambda) / m } else { 0.0 };
+            *re *= gain;
+            *im *= gain;
+        }
+        Ok(())
+    }
+
+    /// Quantize a spectrum to 16-bit integers
+    ///
+    /// Each component is divided by `step` and rounded to the nearest integer,
+    /// saturating at the `i16` range, so `dequantize` recovers it within `step/2`
+    /// unless it saturated.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `step` - Quantization step (must be > 0)
+    ///
+    /// # Returns
+    /// * `Int16Array` - Interleaved quantized real/imag values (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn quantize(&self, input_real: &[f32], input_imag: &[f32], step: f32) -> Result<Vec<i16>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        validate_step(step)?;
+
+        // Float-to-int `as` casts saturate, which is the overflow behavior we want
+        Ok(input_real
+            .iter()
+            .zip(input_imag.iter())
+            .flat_map(|(&re, &im)| [(re / step).round() as i16, (im / step).round() as i16])
+            .collect())
+    }
+
+    /// Reverse `quantize` by multiplying each value by `step`
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved quantized values (length must be 2 * size^3)
+    /// * `step` - The step passed to `quantize` (must be > 0)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag spectrum (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn dequantize(&self, data: &[i16], step: f32) -> Result<Vec<f32>, JsValue> {
+        if data.len() != 2 * self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: 2 * self.total_size,
+                got: data.len(),
+            }
+            .into());
+        }
+        validate_step(step)?;
+        Ok(data.iter().map(|&q| q as f32 * step).collect())
+    }
+
+    /// Scale a spatial kernel so its real parts sum to 1, in place
+    ///
+    /// The sum of the real parts is the kernel's DC coefficient, so dividing
+    /// every sample (real and imaginary) by it makes a convolution with the
+    /// kernel preserve total intensity.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the kernel (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    #[wasm_bindgen]
+    pub fn normalize_kernel(&self, input_real: &mut [f32], input_imag: &mut [f32]) -> Result<(), JsValue> {
+        self.validate_input(input_real, input_imag)?;
+
+        let sum: f32 = input_real.iter().sum();
+        if sum == 0.0 {
+            return Err(FftError::InvalidInput {
+                reason: "Kernel sums to zero and cannot be normalized".to_string(),
+            }
+            .into());
+        }
+
+        let scale = 1.0 / sum;
+        input_real.iter_mut().chain(input_imag.iter_mut()).for_each(|v| *v *= scale);
+        Ok(())
+    }
+
+    /// Zero every coefficient above a normalized cutoff frequency, in place
+    ///
+    /// The normalized radius of a coefficient is `|k| / (size/2)` for signed
+    /// frequency indices, so 1.0 is the Nyquist frequency along an axis.
+    /// Coefficients with radius `<= cutoff` are kept.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `cutoff` - Normalized cutoff in [0, 1]
+    #[wasm_bindgen]
+    pub fn apply_lowpass(&self, input_real: &mut [f32], input_imag: &mut [f32], cutoff: f32) -> Result<(), JsValue> {
+        self.apply_radial_gain(input_real, input_imag, cutoff, 0.0, |r| if r <= cutoff { 1.0 } else { 0.0 })
+    }
+
+    /// Zero every coefficient below a normalized cutoff frequency, in place
+    ///
+    /// Uses the same normalized radius as `apply_lowpass`; coefficients with
+    /// radius `>= cutoff` are kept.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `cutoff` - Normalized cutoff in [0, 1]
+    #[wasm_bindgen]
+    pub fn apply_highpass(&self, input_real: &mut [f32], input_imag: &mut [f32], cutoff: f32) -> Result<(), JsValue> {
+        self.apply_radial_gain(input_real, input_imag, cutoff, 0.0, |r| if r >= cutoff { 1.0 } else { 0.0 })
+    }
+
+    /// Low-pass filter with a smooth transition band, in place
+    ///
+    /// The gain falls from 1 to 0 along `shape` over radii
+    /// `[cutoff - width/2, cutoff + width/2]`, which avoids the ringing of a hard
+    /// edge. A width of 0 is identical to `apply_lowpass`.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `cutoff` - Normalized cutoff in [0, 1], the center of the transition
+    /// * `width` - Width of the transition band (>= 0, and the band must stay within [0, 1])
+    /// * `shape` - Transition shape
+    #[wasm_bindgen]
+    pub fn apply_lowpass_smooth(
+        &self,
+        input_real: &mut [f32],
+        input_imag: &mut [f32],
+        cutoff: f32,
+        width: f32,
+        shape: Rolloff,
+    ) -> Result<(), JsValue> {
+        if width == 0.0 {
+            return self.apply_lowpass(input_real, input_imag, cutoff);
+        }
+        validate_transition(cutoff, cutoff, width)?;
+        self.apply_radial_gain(input_real, input_imag, cutoff, width, |r| 1.0 - shape.step(cutoff, width, r))
+    }
+
+    /// High-pass filter with a smooth transition band, in place
+    ///
+    /// The mirror image of `apply_lowpass_smooth`: the gain rises from 0 to 1 over
+    /// radii `[cutoff - width/2, cutoff + width/2]`.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `cutoff` - Normalized cutoff in [0, 1], the center of the transition
+    /// * `width` - Width of the transition band (>= 0, and the band must stay within [0, 1])
+    /// * `shape` - Transition shape
+    #[wasm_bindgen]
+    pub fn apply_highpass_smooth(
+        &self,
+        input_real: &mut [f32],
+        input_imag: &mut [f32],
+        cutoff: f32,
+        width: f32,
+        shape: Rolloff,
+    ) -> Result<(), JsValue> {
+        if width == 0.0 {
+            return self.apply_highpass(input_real, input_imag, cutoff);
+        }
+        validate_transition(cutoff, cutoff, width)?;
+        self.apply_radial_gain(input_real, input_imag, cutoff, width, |r| shape.step(cutoff, width, r))
+    }
+
+    /// Keep only coefficients in a radial frequency shell, in place
+    ///
+    /// Uses the same normalized radius as `apply_lowpass`. Coefficients with
+    /// `low <= radius <= high` are kept and all others are zeroed.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `low` - Inner edge of the shell in [0, 1]
+    /// * `high` - Outer edge of the shell in [low, 1]
+    #[wasm_bindgen]
+    pub fn apply_bandpass(&self, input_real: &mut [f32], input_imag: &mut [f32], low: f32, high: f32) -> Result<(), JsValue> {
+        validate_band(low, high)?;
+        self.apply_radial_gain(input_real, input_imag, low, 0.0, |r| {
+            if (low..=high).contains(&r) { 1.0 } else { 0.0 }
+        })
+    }
+
+    /// Band-pass filter with smooth transitions at both edges, in place
+    ///
+    /// The gain rises along `shape` over `[low - width/2, low + width/2]`, like
+    /// `apply_highpass_smooth`, and falls over `[high - width/2, high + width/2]`,
+    /// like `apply_lowpass_smooth`. A width of 0 is identical to `apply_bandpass`.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `low` - Inner edge of the shell in [0, 1]
+    /// * `high` - Outer edge of the shell in [low, 1]
+    /// * `width` - Width of each transition band (>= 0, and the bands must stay within [0, 1])
+    /// * `shape` - Transition shape
+    #[wasm_bindgen]
+    pub fn apply_bandpass_smooth(
+        &self,
+        input_real: &mut [f32],
+        input_imag: &mut [f32],
+        low: f32,
+        high: f32,
+        width: f32,
+        shape: Rolloff,
+    ) -> Result<(), JsValue> {
+        if width == 0.0 {
+            return self.apply_bandpass(input_real, input_imag, low, high);
+        }
+        validate_band(low, high)?;
+        validate_transition(low, high, width)?;
+        self.apply_radial_gain(input_real, input_imag, low, width, |r| {
+            shape.step(low, width, r) * (1.0 - shape.step(high, width, r))
+        })
+    }
+
+    /// Zero the coefficients in a radial frequency shell, in place
+    ///
+    /// The complement of `apply_bandpass`: coefficients with
+    /// `low <= radius <= high` are zeroed and all others are kept.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    /// * `low` - Inner edge of the shell in [0, 1]
+    /// * `high` - Outer edge of the shell in [low, 1]
+    #[wasm_bindgen]
+    pub fn apply_notch(&self, input_real: &mut [f32], input_imag: &mut [f32], low: f32, high: f32) -> Result<(), JsValue> {
+        validate_band(low, high)?;
+        self.apply_radial_gain(input_real, input_imag, low, 0.0, |r| {
+            if (low..=high).contains(&r) { 0.0 } else { 1.0 }
+        })
+    }
+
+    /// Multiply coefficients in radial frequency bands by complex gains
+    ///
+    /// Band `i` covers normalized radial frequencies `[band_edges[i], band_edges[i+1])`,
+    /// where the normalized radius is `|k| / (size/2)` for signed frequency indices
+    /// (each axis scaled by its own length for non-cubic transforms), so 1.0 is the
+    /// Nyquist frequency along an axis (corners reach √3).
+    /// Coefficients outside every band are left unchanged.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved spectrum (length must be 2 * size^3), modified in place
+    /// * `band_edges` - Non-decreasing band edges (length = number of bands + 1)
+    /// * `gains_re` - Real part of each band's gain
+    /// * `gains_im` - Imaginary part of each band's gain (same length as `gains_re`)
+    #[wasm_bindgen]
+    pub fn apply_band_gains(
+        &self,
+        data: &mut [f32],
+        band_edges: &[f32],
+        gains_re: &[f32],
+        gains_im: &[f32],
+    ) -> Result<(), JsValue> {
+        self.validate_interleaved(data)?;
+        if gains_re.len() != gains_im.len() || band_edges.len() != gains_re.len() + 1 {
+            return Err(FftError::InvalidLength {
+                got: band_edges.len(),
+                expected: format!(
+                    "one more band edge than gains ({} real, {} imaginary)",
+                    gains_re.len(),
+                    gains_im.len()
+                ),
+            }
+            .into());
+        }
+        if band_edges.windows(2).any(|w| w[1] < w[0]) {
+            return Err(FftError::InvalidArgument {
+                name: "band edges",
+                reason: "must be non-decreasing".to_string(),
+            }
+            .into());
+        }
+
+        for (i, c) in data.chunks_exact_mut(2).enumerate() {
+            let radius = self.normalized_radius(i);
+            let band = band_edges.windows(2).position(|w| radius >= w[0] && radius < w[1]);
+            if let Some(band) = band {
+                let gained = Complex::new(c[0], c[1]) * Complex::new(gains_re[band], gains_im[band]);
+                c[0] = gained.re;
+                c[1] = gained.im;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply a real filter mask with zero net phase shift
+    ///
+    /// Equivalent to forward-backward (filtfilt) filtering: each coefficient is
+    /// multiplied by the squared mask `|H|²`, which is real and non-negative, so
+    /// no phase distortion is introduced.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved spectrum (length must be 2 * size^3), modified in place
+    /// * `filter_mask` - Real filter response per coefficient (length must be size^3)
+    #[wasm_bindgen]
+    pub fn apply_zerophase(&self, data: &mut [f32], filter_mask: &[f32]) -> Result<(), JsValue> {
+        self.validate_interleaved(data)?;
+        if filter_mask.len() != self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: self.total_size,
+                got: filter_mask.len(),
+            }
+            .into());
+        }
+
+        for (c, &h) in data.chunks_exact_mut(2).zip(filter_mask.iter()) {
+            let gain = h * h;
+            c[0] *= gain;
+            c[1] *= gain;
+        }
+
+        Ok(())
+    }
+
+    /// Move the zero-frequency coefficient to the center of the spectrum
+    ///
+    /// Rolls each axis forward by `floor(n/2)`, so index 0 lands at `n/2` and the
+    /// negative frequencies come first, which is the natural layout for display.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved spectrum (length must be 2 * size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Shifted interleaved spectrum
+    #[wasm_bindgen]
+    pub fn fftshift(&self, data: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_interleaved(data)?;
+        Ok(self.roll(data, self.dims.map(|n| n / 2)))
+    }
+
+    /// Undo `fftshift`
+    ///
+    /// Rolls each axis back by `floor(n/2)` (forward by `ceil(n/2)`), so
+    /// `ifftshift(fftshift(x)) == x` for odd lengths as well.
+    ///
+    /// # Arguments
+    /// * `data` - Centered interleaved spectrum (length must be 2 * size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved spectrum in standard FFT order
+    #[wasm_bindgen]
+    pub fn ifftshift(&self, data: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_interleaved(data)?;
+        Ok(self.roll(data, self.dims.map(|n| n - n / 2)))
+    }
+
+    /// Rotate the phase of every coefficient by a constant angle
+    ///
+    /// Multiplies each interleaved coefficient by `exp(i·angle)`. A rotated
+    /// spectrum of a real field is generally no longer Hermitian, so its inverse
+    /// is complex: use it before an `inverse` that keeps the complex output, or
+    /// take the real part afterwards for a deliberate visual effect.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved real/imag coefficients (length must be 2 * size^3), modified in place
+    /// * `angle` - Rotation in radians
+    #[wasm_bindgen]
+    pub fn rotate_global_phase(&self, data: &mut [f32], angle: f32) -> Result<(), JsValue> {
+        self.validate_interleaved(data)?;
+
+        let rotation = Complex::from_polar(1.0f32, angle);
+        for c in data.chunks_exact_mut(2) {
+            let rotated = Complex::new(c[0], c[1]) * rotation;
+            c[0] = rotated.re;
+            c[1] = rotated.im;
+        }
+        Ok(())
+    }
+
+    /// Locate the largest error of a forward+inverse roundtrip
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - `[flat_index, error_magnitude]` for the voxel whose
+    ///   reconstructed value differs most from the input (complex distance)
+    #[wasm_bindgen]
+    pub fn worst_roundtrip_error(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        let reconstructed = self.roundtrip(input_real, input_imag)?;
+
+        let (index, error) = reconstructed
+            .iter()
+            .zip(input_real.iter().zip(input_imag.iter()))
+            .map(|(c, (&re, &im))| (c - Complex::new(re, im)).norm())
+            .enumerate()
+            .fold((0, 0.0f32), |worst, (i, e)| if e > worst.1 { (i, e) } else { worst });
+
+        Ok(vec![index as f32, error])
+    }
+
+    /// Check Parseval's theorem for a field
+    ///
+    /// Computes the spatial energy with `calculate_energy`, forward-transforms,
+    /// and compares the spectral energy against the spatial energy times the
+    /// factor implied by the normalization mode (`size^3` for `Backward`).
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the field (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    ///
+    /// # Returns
+    /// * Relative energy error, or 0 for an all-zero field
+    #[wasm_bindgen]
+    pub fn verify_parseval(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<f32, JsValue> {
+        Ok(self.parseval_error(input_real, input_imag)?)
+    }
+
+    /// Compute the monogenic signal of a real field
+    ///
+    /// The three Riesz components are obtained with the spectral multipliers
+    /// `i·f_j / |f|`, where `f_j` is the signed frequency along axis `j` in cycles
+    /// per voxel, and inverse-transformed. The DC mode has no direction and is set
+    /// to zero in every component. Only real parts are kept, which also removes
+    /// the unpaired Nyquist terms. Local amplitude is
+    /// `sqrt(f² + r_x² + r_y² + r_z²)`, local phase `atan2(|r|, f)` and local
+    /// orientation the direction of `(r_x, r_y, r_z)`.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real field (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Four concatenated fields of length size^3: the original
+    ///   field followed by the X, Y and Z Riesz components
+    #[wasm_bindgen]
+    pub fn monogenic_signal(&mut self, input_real: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_real(input_real)?;
+
+        let mut spectrum = try_alloc(self.total_size, Complex::new(0.0f32, 0.0), self.size)?;
+        for (c, &re) in spectrum.iter_mut().zip(input_real) {
+            c.re = re;
+        }
+        self.fft_3d(&mut spectrum, true)?;
+
+        let [nx, ny, nz] = self.dims;
+        let frequency = |i: usize| {
+            [
+                signed_frequency(i % nx, nx) / nx as f32,
+                signed_frequency((i / nx) % ny, ny) / ny as f32,
+                signed_frequency(i / (nx * ny), nz) / nz as f32,
+            ]
+        };
+
+        let mut output = try_alloc(4 * self.total_size, 0.0f32, self.size)?;
+        output[..self.total_size].copy_from_slice(input_real);
+
+        let mut component = try_alloc(self.total_size, Complex::new(0.0f32, 0.0), self.size)?;
+        for (axis, riesz) in output[self.total_size..].chunks_exact_mut(self.total_size).enumerate() {
+            for (i, (c, s)) in component.iter_mut().zip(spectrum.iter()).enumerate() {
+                let f = frequency(i);
+                let radius = (f[0] * f[0] + f[1] * f[1] + f[2] * f[2]).sqrt();
+                *c = if radius > 0.0 {
+                    *s * Complex::new(0.0, f[axis] / radius)
+                } else {
+                    Complex::new(0.0, 0.0)
+                };
+            }
+            self.fft_3d(&mut component, false)?;
+            let scale = self.normalization_factor();
+            for (r, c) in riesz.iter_mut().zip(component.iter()) {
+                *r = c.re * scale;
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Estimate the correlation length of a real field
+    ///
+    /// The mean is removed, the autocorrelation is computed via the power spectrum
+    /// (Wiener–Khinchin), normalized by its zero-lag value and averaged over shells
+    /// of equal lag radius. The result is the radius at which this profile first
+    /// drops below 1/e, linearly interpolated between integer radii.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real field (length must be size^3)
+    ///
+    /// # Returns
+    /// * `f32` - Correlation length in voxels, capped at size/2 if the profile
+    ///   never drops below 1/e; errors for a constant field
+    #[wasm_bindgen]
+    pub fn correlation_length(&mut self, input_real: &[f32]) -> Result<f32, JsValue> {
+        self.require_cubic()?;
+        self.validate_real(input_real)?;
+
+        let mean = input_real.iter().sum::<f32>() / self.total_size as f32;
+        let mut buffer = self.real_buffer(input_real)?;
+        buffer.iter_mut().for_each(|c| c.re -= mean);
+
+        self.autocorrelate(&mut buffer)?;
+
+        let zero_lag = buffer[0].re;
+        if zero_lag <= 0.0 {
+            return Err(FftError::InvalidInput {
+                reason: "Field is constant; correlation length is undefined".to_string(),
+            }
+            .into());
+        }
+
+        let mut autocorrelation = self.real_parts(&buffer)?;
+        autocorrelation.iter_mut().for_each(|v| *v /= zero_lag);
+        let profile = radial_profile(&autocorrelation, self.size);
+
+        let threshold = (-1.0f32).exp();
+        for r in 1..profile.len() {
+            if profile[r] < threshold {
+                let t = (profile[r - 1] - threshold) / (profile[r - 1] - profile[r]);
+                return Ok((r - 1) as f32 + t);
+            }
+        }
+
+        Ok((self.size / 2) as f32)
+    }
+
+    /// Compute the 3D autocorrelation of a field
+    ///
+    /// By the Wiener–Khinchin theorem the autocorrelation is the inverse transform
+    /// of the power spectrum `|c|²`. Lags wrap around (the field is treated as
+    /// periodic), and the value at lag 0 is the total energy `Σ|x|²` in every
+    /// normalization mode.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of the field (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Autocorrelation indexed by lag (length = size^3)
+    #[wasm_bindgen]
+    pub fn autocorrelation(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.autocorrelate(&mut buffer)?;
+        Ok(self.real_parts(&buffer)?)
+    }
+
+    /// Transform a field and compute a bundle of spectral descriptors
+    ///
+    /// Radial frequencies are measured in cycles per voxel, `|f| = |k| / size`
+    /// with signed per-axis indices `k`.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Fixed layout of six values:
+    ///   - `[0]` energy: spectral energy `Σ|c|²`
+    ///   - `[1]` centroid: power-weighted mean of `|f|`
+    ///   - `[2]` bandwidth: power-weighted standard deviation of `|f|`
+    ///   - `[3]` flatness: geometric mean over arithmetic mean of the power
+    ///   - `[4]` dominant_wavelength: `1 / |f|` of the strongest non-DC
+    ///     coefficient in voxels (0 if there is none)
+    ///   - `[5]` effective_modes: participation ratio `(Σp)² / Σp²`
+    ///
+    ///   All values are 0 for an all-zero field.
+    #[wasm_bindgen]
+    pub fn forward_descriptors(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.require_cubic()?;
+        self.validate_input(input_real, input_imag)?;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+
+        let n = self.size;
+        let mut powers = try_alloc(self.total_size, 0.0f32, n)?;
+        for (p, c) in powers.iter_mut().zip(buffer.iter()) {
+            *p = c.norm_sqr();
+        }
+        let radius = |i: usize| {
+            let fx = signed_frequency(i % n, n);
+            let fy = signed_frequency((i / n) % n, n);
+            let fz = signed_frequency(i / (n * n), n);
+            (fx * fx + fy * fy + fz * fz).sqrt() / n as f32
+        };
+
+        let energy: f64 = powers.iter().map(|&p| p as f64).sum();
+        if energy == 0.0 {
+            return Ok(vec![0.0; 6]);
+        }
+
+        let mut weighted = 0.0f64;
+        let mut weighted_sq = 0.0f64;
+        let mut log_sum = 0.0f64;
+        let mut has_zero = false;
+        let mut dominant = (0usize, 0.0f32);
+        for (i, &p) in powers.iter().enumerate() {
+            let f = radius(i) as f64;
+            weighted += p as f64 * f;
+            weighted_sq += p as f64 * f * f;
+            if p > 0.0 {
+                log_sum += (p as f64).ln();
+            } else {
+                has_zero = true;
+            }
+            if i != 0 && p > dominant.1 {
+                dominant = (i, p);
+            }
+        }
+
+        let centroid = weighted / energy;
+        let bandwidth = (weighted_sq / energy - centroid * centroid).max(0.0).sqrt();
+        let count = powers.len() as f64;
+        let flatness = if has_zero { 0.0 } else { (log_sum / count).exp() / (energy / count) };
+        let dominant_wavelength = if dominant.1 > 0.0 { 1.0 / radius(dominant.0) } else { 0.0 };
+
+        Ok(vec![
+            energy as f32,
+            centroid as f32,
+            bandwidth as f32,
+            flatness as f32,
+            dominant_wavelength,
+            participation_ratio(&powers),
+        ])
+    }
+
+    /// Perform forward 3D FFT on a cubic region of a larger buffer, in place
+    ///
+    /// The `size^3` region starting at the origin voxel is gathered from the
+    /// parent using the parent's strides, transformed, and written back into the
+    /// same voxels. Data outside the region is left untouched.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved parent buffer (length must be 2 * parent_size^3)
+    /// * `parent_size` - Size of each dimension of the parent cube
+    /// * `origin_x` - X index of the region's first voxel
+    /// * `origin_y` - Y index of the region's first voxel
+    /// * `origin_z` - Z index of the region's first voxel
+    #[wasm_bindgen]
+    pub fn forward_in_place_region(
+        &mut self,
+        data: &mut [f32],
+        parent_size: usize,
+        origin_x: usize,
+        origin_y: usize,
+        origin_z: usize,
+    ) -> Result<(), JsValue> {
+        self.require_cubic()?;
+        let expected = cube_volume(parent_size)?
+            .checked_mul(2)
+            .ok_or_else(|| volume_overflow(parent_size))?;
+        if data.len() != expected {
+            return Err(FftError::LengthMismatch {
+                expected,
+                got: data.len(),
+            }
+            .into());
+        }
+        let n = self.size;
+        validate_region([origin_x, origin_y, origin_z], n, parent_size)?;
+
+        let parent_index = |x: usize, y: usize, z: usize| {
+            ((origin_z + z) * parent_size * parent_size + (origin_y + y) * parent_size + origin_x + x) * 2
+        };
+
+        let mut buffer = try_alloc(self.total_size, Complex::new(0.0f32, 0.0f32), n)?;
+        for (i, c) in buffer.iter_mut().enumerate() {
+            let p = parent_index(i % n, (i / n) % n, i / (n * n));
+            *c = Complex::new(data[p], data[p + 1]);
+        }
+
+        self.fft_3d(&mut buffer, true)?;
+        self.flush_output(&mut buffer);
+
+        for (i, c) in buffer.iter().enumerate() {
+            let p = parent_index(i % n, (i / n) % n, i / (n * n));
+            data[p] = c.re;
+            data[p + 1] = c.im;
+        }
+
+        Ok(())
+    }
+
+    /// Compute the windowed spectrum of a block around a point of a larger grid
+    ///
+    /// Gathers the `size^3` block spanning `center - size/2 .. center + size/2`
+    /// along each axis from a `parent_size^3` interleaved grid, clamping indices
+    /// that fall outside the grid to its nearest face. The block is multiplied by
+    /// the separable `window` (as in `apply_window`) and forward-transformed.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved parent grid (length must be 2 * parent_size^3)
+    /// * `parent_size` - Size of each dimension of the parent cube
+    /// * `center_x` - X index of the block center (must be < parent_size)
+    /// * `center_y` - Y index of the block center (must be < parent_size)
+    /// * `center_z` - Z index of the block center (must be < parent_size)
+    /// * `window` - Window applied along every axis of the block
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved spectrum of the block (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn local_spectrum(
+        &mut self,
+        data: &[f32],
+        parent_size: usize,
+        center_x: usize,
+        center_y: usize,
+        center_z: usize,
+        window: Window,
+    ) -> Result<Vec<f32>, JsValue> {
+        self.require_cubic()?;
+        let expected = cube_volume(parent_size)?
+            .checked_mul(2)
+            .ok_or_else(|| volume_overflow(parent_size))?;
+        if data.len() != expected {
+            return Err(FftError::LengthMismatch {
+                expected,
+                got: data.len(),
+            }
+            .into());
+        }
+        let center = [center_x, center_y, center_z];
+        if center.iter().any(|&c| c >= parent_size) {
+            return Err(FftError::InvalidArgument {
+                name: "center",
+                reason: format!(
+                    "({}, {}, {}). Expected indices below parent size {}",
+                    center_x, center_y, center_z, parent_size
+                ),
+            }
+            .into());
+        }
+
+        // Parent index of block offset `k` along an axis, clamped to the grid
+        let n = self.size;
+        let parent_axis = |c: usize, k: usize| (c + k).saturating_sub(n / 2).min(parent_size - 1);
+        let w = self.window_coefficients(window, n);
+
+        let mut buffer = try_alloc(self.total_size, Complex::new(0.0f32, 0.0f32), n)?;
+        for (i, c) in buffer.iter_mut().enumerate() {
+            let (x, y, z) = (i % n, (i / n) % n, i / (n * n));
+            let p = ((parent_axis(center_z, z) * parent_size + parent_axis(center_y, y)) * parent_size
+                + parent_axis(center_x, x))
+                * 2;
+            *c = Complex::new(data[p], data[p + 1]) * (w[x] * w[y] * w[z]);
+        }
+
+        self.fft_3d(&mut buffer, true)?;
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Compute the matched-filter response of a real signal to a real template
+    ///
+    /// The response is the circular cross-correlation
+    /// `IFFT(FFT(signal) · conj(FFT(template)))` divided by the template energy
+    /// `Σ template²`. Entry `d` scores the template shifted by `d` voxels (with
+    /// wrap-around): a peak of 1.0 means the signal contains an exact copy of the
+    /// template at that offset, and a copy scaled by `a` gives a peak of `a`.
+    ///
+    /// # Arguments
+    /// * `signal_real` - Real signal (length must be size^3)
+    /// * `template_real` - Real template (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Response map (length = size^3); errors for an all-zero template
+    #[wasm_bindgen]
+    pub fn matched_filter(&mut self, signal_real: &[f32], template_real: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_real(signal_real)?;
+        self.validate_real(template_real)?;
+
+        let template_energy: f32 = template_real.iter().map(|&t| t * t).sum();
+        if template_energy == 0.0 {
+            return Err(FftError::InvalidInput {
+                reason: "Template has zero energy".to_string(),
+            }
+            .into());
+        }
+
+        let mut signal = self.real_buffer(signal_real)?;
+        let mut template = self.real_buffer(template_real)?;
+        self.fft_3d(&mut signal, true)?;
+        self.fft_3d(&mut template, true)?;
+
+        // The product carries the forward scale twice; undo one of them
+        let unscale = 1.0 / self.forward_scale();
+        for (s, t) in signal.iter_mut().zip(template.iter()) {
+            *s *= t.conj() * unscale;
+        }
+        self.fft_3d(&mut signal, false)?;
+
+        let scale = self.normalization_factor() / template_energy;
+        let mut response = self.real_parts(&signal)?;
+        response.iter_mut().for_each(|v| *v *= scale);
+        Ok(response)
+    }
+
+    /// Compute the phase correlation surface of two volumes
+    ///
+    /// Both volumes are transformed and the normalized cross-power spectrum
+    /// `A·conj(B) / |A·conj(B)|` is inverse-transformed. Bins where the product
+    /// is zero, or below `f32::EPSILON` times the largest product, contribute
+    /// nothing instead of dividing by (nearly) zero. If `a` is
+    /// `b` circularly shifted by `d` voxels, the surface has a peak of about 1.0
+    /// at offset `d`; use `find_peak_offset` to locate it. The result does not
+    /// depend on the normalization mode.
+    ///
+    /// # Arguments
+    /// * `a_real` - Real parts of the first volume (length must be size^3)
+    /// * `a_imag` - Imaginary parts of the first volume (length must be size^3)
+    /// * `b_real` - Real parts of the second volume (length must be size^3)
+    /// * `b_imag` - Imaginary parts of the second volume (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved complex correlation surface (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn phase_correlation(
+        &mut self,
+        a_real: &[f32],
+        a_imag: &[f32],
+        b_real: &[f32],
+        b_imag: &[f32],
+    ) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(a_real, a_imag)?;
+        self.validate_input(b_real, b_imag)?;
+
+        let mut a = self.complex_buffer(a_real, a_imag)?;
+        let mut b = self.complex_buffer(b_real, b_imag)?;
+        self.fft_3d(&mut a, true)?;
+        self.fft_3d(&mut b, true)?;
+
+        // Bins far below the strongest one are numerical noise; drop them rather
+        // than blow them up to unit magnitude
+        let mut largest = 0.0f32;
+        for (x, y) in a.iter_mut().zip(b.iter()) {
+            *x *= y.conj();
+            largest = largest.max(x.norm());
+        }
+        let threshold = largest * f32::EPSILON;
+        for x in a.iter_mut() {
+            let norm = x.norm();
+            *x = if norm > threshold { *x / norm } else { Complex::new(0.0, 0.0) };
+        }
+
+        self.fft_3d(&mut a, false)?;
+        // Undo the mode's inverse scaling so the peak height is always ~1
+        let scale = self.normalization_factor() * self.forward_scale();
+        for c in a.iter_mut() {
+            *c *= scale;
+        }
+
+        Ok(self.transform_output(&mut a)?)
+    }
+
+    /// Locate the strongest peak of an interleaved surface as a signed offset
+    ///
+    /// Finds the coefficient with the largest magnitude and reports its position
+    /// per axis, wrapping indices above `n/2` to negative offsets.
+    ///
+    /// # Arguments
+    /// * `surface` - Interleaved complex surface, e.g. from `phase_correlation`
+    ///   (length must be 2 * size^3)
+    ///
+    /// # Returns
+    /// * `Int32Array` - `[dx, dy, dz]` of the maximum
+    #[wasm_bindgen]
+    pub fn find_peak_offset(&self, surface: &[f32]) -> Result<Vec<i32>, JsValue> {
+        self.validate_interleaved(surface)?;
+
+        let (peak, _) = surface
+            .chunks_exact(2)
+            .map(|c| c[0] * c[0] + c[1] * c[1])
+            .enumerate()
+            .fold((0, f32::MIN), |best, (i, m)| if m > best.1 { (i, m) } else { best });
+
+        let [nx, ny, nz] = self.dims;
+        Ok(vec![
+            signed_frequency(peak % nx, nx) as i32,
+            signed_frequency((peak / nx) % ny, ny) as i32,
+            signed_frequency(peak / (nx * ny), nz) as i32,
+        ])
+    }
+
+    /// Find the strongest non-DC coefficient of a spectrum
+    ///
+    /// The DC bin at index 0 is skipped. For a real field the peaks at `+k` and
+    /// `-k` have equal magnitude, and the one with the lower flat index is
+    /// reported.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of a spectrum in standard FFT order (length must be size^3)
+    /// * `input_imag` - Imaginary parts (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - `[fx, fy, fz, magnitude]`, with signed integer frequency
+    ///   indices (bins above n/2 wrap to negative frequencies)
+    #[wasm_bindgen]
+    pub fn dominant_frequency(&self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+
+        let (peak, magnitude) = input_real
+            .iter()
+            .zip(input_imag.iter())
+            .map(|(&re, &im)| Complex::new(re, im).norm())
+            .enumerate()
+            .skip(1)
+            .fold((0, f32::MIN), |best, (i, m)| if m > best.1 { (i, m) } else { best });
+
+        let [nx, ny, nz] = self.dims;
+        Ok(vec![
+            signed_frequency(peak % nx, nx),
+            signed_frequency((peak / nx) % ny, ny),
+            signed_frequency(peak / (nx * ny), nz),
+            magnitude,
+        ])
+    }
+
+    /// Perform forward 3D FFT of the per-voxel magnitude of a complex field
+    ///
+    /// Computes `sqrt(re² + im²)` for every voxel and transforms the resulting
+    /// real field, fusing the magnitude pass with the transform. The output is the
+    /// spectrum of a real field, so it is Hermitian symmetric.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_of_magnitude(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+
+        let mut buffer = try_alloc(self.total_size, Complex::new(0.0f32, 0.0), self.size)?;
+        for ((c, &re), &im) in buffer.iter_mut().zip(input_real).zip(input_imag) {
+            c.re = re.hypot(im);
+        }
+
+        self.fft_3d(&mut buffer, true)?;
+        Ok(self.transform_output(&mut buffer)?)
+    }
+
+    /// Perform forward 3D FFT and report how long each axis pass took
+    ///
+    /// Pass times come from the per-pass progress hook of the transform, so
+    /// plain `forward` carries no timing overhead. The X time also covers the
+    /// plan lookup and scratch allocation that precede the first pass.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    ///
+    /// # Returns
+    /// * `TimedFftResult` - Interleaved spectrum and per-axis durations
+    #[wasm_bindgen]
+    pub fn forward_timed(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<TimedFftResult, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+
+        let mut marks = [0.0f64; 4];
+        let mut passes = 0;
+        marks[0] = now_ms();
+        self.fft_3d_with_progress(
+            &mut buffer,
+            true,
+            Some(&mut |_| {
+                passes = (passes + 1).min(3);
+                marks[passes] = now_ms();
+            }),
+        )?;
+
+        Ok(TimedFftResult {
+            spectrum: self.transform_output(&mut buffer)?,
+            x_pass_ms: marks[1] - marks[0],
+            y_pass_ms: marks[2] - marks[1],
+            z_pass_ms: marks[3] - marks[2],
+        })
+    }
+
+    /// Perform forward 3D FFT and return the spectrum with derived quantities
+    ///
+    /// The spectral energy is accumulated while the coefficients are written to
+    /// the interleaved output, so it costs no extra pass.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    ///
+    /// # Returns
+    /// * `FftResult` - Spectrum, energy and (lazily) magnitudes
+    #[wasm_bindgen]
+    pub fn forward_full(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<FftResult, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+        self.flush_output(&mut buffer);
+
+        let mut spectrum = try_alloc(2 * buffer.len(), 0.0f32, self.size)?;
+        let mut energy = 0.0f32;
+        for (out, c) in spectrum.chunks_exact_mut(2).zip(buffer.iter()) {
+            out[0] = c.re;
+            out[1] = c.im;
+            energy += c.norm_sqr();
+        }
+
+        Ok(FftResult {
+            spectrum,
+            energy,
+            magnitude: OnceCell::new(),
+        })
+    }
+
+    /// Perform forward 3D FFT on a paired complex field (space -> frequency)
+    ///
+    /// # Arguments
+    /// * `field` - Complex input field (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_field(&mut self, field: &ComplexField) -> Result<Vec<f32>, JsValue> {
+        self.forward(&field.real, &field.imag)
+    }
+
+    /// Validate input arrays
+    /// Relative Parseval energy error of a field, shared by `verify_parseval`
+    /// and `check_parseval`
+    fn parseval_error(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<f32, FftError> {
+        self.validate_input(input_real, input_imag)?;
+        let spatial = pair_energy(input_real, input_imag)?;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+        let spectral_real: Vec<f32> = buffer.iter().map(|c| c.re).collect();
+        let spectral_imag: Vec<f32> = buffer.iter().map(|c| c.im).collect();
+        let spectral = pair_energy(&spectral_real, &spectral_imag)?;
+
+        let scale = self.forward_scale();
+        let expected = spatial * self.total_size as f32 * scale * scale;
+        if expected == 0.0 {
+            return Ok(if spectral == 0.0 { 0.0 } else { f32::INFINITY });
+        }
+        Ok((spectral - expected).abs() / expected)
+    }
+
+    fn validate_input(&self, input_real: &[f32], input_imag: &[f32]) -> Result<(), FftError> {
+        self.validate_real(input_real)?;
+        if input_imag.len() != self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: self.total_size,
+                got: input_imag.len(),
+            });
+        }
+        self.validate_finite(input_imag)
+    }
+
+    /// Run a forward transform followed by a normalized inverse transform
+    fn roundtrip(&mut self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<Complex<f32>>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+        self.fft_3d(&mut buffer, false)?;
+        self.normalize_inverse(&mut buffer);
+
+        Ok(buffer)
+    }
+
+    /// Build a complex buffer from separate real and imaginary arrays
+    fn complex_buffer(&self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<Complex<f32>>, FftError> {
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(input_real.len())
+            .map_err(|_| out_of_memory(self.size))?;
+        buffer.extend(
+            input_real
+                .iter()
+                .zip(input_imag.iter())
+                .map(|(&re, &im)| Complex::new(re, im)),
+        );
+        Ok(buffer)
+    }
+
+    /// Build a complex buffer from a real array, with zero imaginary parts
+    fn real_buffer(&self, input_real: &[f32]) -> Result<Vec<Complex<f32>>, FftError> {
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(input_real.len())
+            .map_err(|_| out_of_memory(self.size))?;
+        buffer.extend(input_real.iter().map(|&re| Complex::new(re, 0.0)));
+        Ok(buffer)
+    }
+
+    /// Collect the real parts of a complex buffer
+    fn real_parts(&self, buffer: &[Complex<f32>]) -> Result<Vec<f32>, FftError> {
+        let mut output = try_alloc(buffer.len(), 0.0f32, self.size)?;
+        for (out, c) in output.iter_mut().zip(buffer.iter()) {
+            *out = c.re;
+        }
+        Ok(output)
+    }
+
+    /// Build a complex buffer from interleaved real/imag data
+    fn deinterleave(&self, data: &[f32]) -> Result<Vec<Complex<f32>>, FftError> {
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(data.len() / 2)
+            .map_err(|_| out_of_memory(self.size))?;
+        buffer.extend(data.chunks_exact(2).map(|c| Complex::new(c[0], c[1])));
+        Ok(buffer)
+    }
+
+    /// Forward-transform interleaved data into `dst` through the work buffer
+    ///
+    /// Reads `src`, or `dst` itself when `src` is `None`, and applies the same
+    /// post-processing as `forward`. `dst` is left unchanged on error.
+    fn forward_to_slice(&mut self, src: Option<&[f32]>, dst: &mut [f32]) -> Result<(), FftError> {
+        let mut buffer = self.take_work(dst.len() / 2)?;
+        buffer.extend(src.unwrap_or(dst).chunks_exact(2).map(|c| Complex::new(c[0], c[1])));
+
+        let result = self.fft_3d(&mut buffer, true);
+        if result.is_ok() {
+            self.flush_output(&mut buffer);
+            write_interleaved(&buffer, dst);
+        }
+
+        self.work = buffer;
+        result
+    }
+
+    /// Take the reusable work buffer, emptied and with room for `len` values
+    ///
+    /// Hand it back by assigning `self.work` once done.
+    fn take_work(&mut self, len: usize) -> Result<Vec<Complex<f32>>, FftError> {
+        let mut buffer = std::mem::take(&mut self.work);
+        buffer.clear();
+        buffer.try_reserve_exact(len).map_err(|_| out_of_memory(self.size))?;
+        Ok(buffer)
+    }
+
+    /// Post-process a transform result: zero subnormals if enabled, then
+    /// interleave
+    ///
+    /// Every method returning a transformed volume goes through this (or calls
+    /// `flush_output` itself when it writes a different layout), so the
+    /// `flush_subnormals` flag behaves the same everywhere.
+    fn transform_output(&self, buffer: &mut [Complex<f32>]) -> Result<Vec<f32>, FftError> {
+        self.flush_output(buffer);
+        self.interleave(buffer)
+    }
+
+    /// Convert a complex buffer to interleaved real/imag output
+    fn interleave(&self, buffer: &[Complex<f32>]) -> Result<Vec<f32>, FftError> {
+        let mut output = try_alloc(2 * buffer.len(), 0.0f32, self.size)?;
+        write_interleaved(buffer, &mut output);
+        Ok(output)
+    }
+
+    /// Validate a real-only input array
+    fn validate_real(&self, input_real: &[f32]) -> Result<(), FftError> {
+        if input_real.len() != self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: self.total_size,
+                got: input_real.len(),
+            });
+        }
+        self.validate_finite(input_real)
+    }
+
+    /// In strict validation mode, reject the first NaN or infinite value
+    fn validate_finite(&self, input: &[f32]) -> Result<(), FftError> {
+        if !self.strict_validation {
+            return Ok(());
+        }
+        match input.iter().position(|v| !v.is_finite()) {
+            Some(index) => Err(FftError::NonFinite { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Validate the length of an interleaved complex buffer
+    fn validate_interleaved(&self, output: &[f32]) -> Result<(), FftError> {
+        if output.len() != 2 * self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: 2 * self.total_size,
+                got: output.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Validate filter parameters and scale each coefficient by `gain(radius)`
+    fn apply_radial_gain(
+        &self,
+        input_real: &mut [f32],
+        input_imag: &mut [f32],
+        cutoff: f32,
+        width: f32,
+        gain: impl Fn(f32) -> f32,
+    ) -> Result<(), JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        if !(0.0..=1.0).contains(&cutoff) {
+            return Err(FftError::InvalidArgument {
+                name: "cutoff",
+                reason: format!("{}. Expected a value in [0, 1]", cutoff),
+            }
+            .into());
+        }
+        if width.is_nan() || width < 0.0 {
+            return Err(FftError::InvalidArgument {
+                name: "transition width",
+                reason: format!("{}. Expected >= 0", width),
+            }
+            .into());
+        }
+
+        for (i, (re, im)) in input_real.iter_mut().zip(input_imag.iter_mut()).enumerate() {
+            let g = gain(self.normalized_radius(i));
+            *re *= g;
+            *im *= g;
+        }
+
+        Ok(())
+    }
+
+    /// Circularly roll interleaved data forward by `shift[axis]` along each axis
+    fn roll(&self, data: &[f32], shift: [usize; 3]) -> Vec<f32> {
+        let [nx, ny, nz] = self.dims;
+        let mut output = vec![0.0f32; data.len()];
+        for (i, c) in data.chunks_exact(2).enumerate() {
+            let x = (i % nx + shift[0]) % nx;
+            let y = ((i / nx) % ny + shift[1]) % ny;
+            let z = (i / (nx * ny) + shift[2]) % nz;
+            let j = 2 * ((z * ny + y) * nx + x);
+            output[j..j + 2].copy_from_slice(c);
+        }
+        output
+    }
+
+    /// Cached 1D coefficients of `window` for an axis of length `n`
+    fn window_coefficients(&self, window: Window, n: usize) -> Rc<[f32]> {
+        let mut cache = self.window_cache.borrow_mut();
+        Rc::clone(
+            cache
+                .entry((window, n))
+                .or_insert_with(|| window.coefficients(n).into()),
+        )
+    }
+
+    /// Replace a field with its autocorrelation, the inverse transform of its
+    /// power spectrum, scaled so lag 0 is the total energy in every mode
+    fn autocorrelate(&mut self, buffer: &mut [Complex<f32>]) -> Result<(), JsValue> {
+        self.fft_3d(buffer, true)?;
+        // The forward scale appears squared in |c|² but only once is undone by
+        // the inverse, so divide the remaining factor out here
+        let scale = self.forward_scale();
+        for c in buffer.iter_mut() {
+            *c = Complex::new(c.norm_sqr() / scale, 0.0);
+        }
+        self.fft_3d(buffer, false)?;
+        self.normalize_inverse(buffer);
+        Ok(())
+    }
+
+    /// Reject methods that are only defined for cubic transforms
+    fn require_cubic(&self) -> Result<(), FftError> {
+        let [nx, ny, nz] = self.dims;
+        if nx != ny || ny != nz {
+            return Err(FftError::NonCubic { dims: self.dims });
+        }
+        Ok(())
+    }
+
+    /// Radial frequency of coefficient `i`, normalized so each axis Nyquist is 1.0
+    fn normalized_radius(&self, i: usize) -> f32 {
+        let [nx, ny, nz] = self.dims;
+        let fx = signed_frequency(i % nx, nx) / (nx as f32 / 2.0);
+        let fy = signed_frequency((i / nx) % ny, ny) / (ny as f32 / 2.0);
+        let fz = signed_frequency(i / (nx * ny), nz) / (nz as f32 / 2.0);
+        (fx * fx + fy * fy + fz * fz).sqrt()
+    }
+
+    /// Flat index of the coefficient at the negated frequency of index `i`
+    fn mirror_index(&self, i: usize) -> usize {
+        let [nx, ny, nz] = self.dims;
+        let (x, y, z) = (i % nx, (i / nx) % ny, i / (nx * ny));
+        ((nz - z) % nz) * nx * ny + ((ny - y) % ny) * nx + (nx - x) % nx
+    }
+
+    /// Make a spectrum Hermitian by averaging each coefficient with its mirror
+    fn enforce_hermitian(&self, buffer: &mut [Complex<f32>]) {
+        for i in 0..buffer.len() {
+            let m = self.mirror_index(i);
+            if m < i {
+                continue;
+            }
+            let average = (buffer[i] + buffer[m].conj()) * 0.5;
+            buffer[i] = average;
+            buffer[m] = average.conj();
+        }
+    }
+
+    /// Zero subnormal components if subnormal flushing is enabled
+    fn flush_output(&self, buffer: &mut [Complex<f32>]) {
+        if !self.flush_subnormals {
+            return;
+        }
+        for c in buffer.iter_mut() {
+            if c.re.is_subnormal() {
+                c.re = 0.0;
+            }
+            if c.im.is_subnormal() {
+                c.im = 0.0;
+            }
+        }
+    }
+
+    /// Overall scale `fft_3d` applies in the forward direction under the current mode
+    fn forward_scale(&self) -> f32 {
+        match self.norm_mode {
+            NormMode::Backward => 1.0,
+            NormMode::Forward => 1.0 / (self.total_size as f32),
+            NormMode::Ortho | NormMode::OrthonormalPerAxis => 1.0 / (self.total_size as f32).sqrt(),
+        }
+    }
+
+    /// Apply the standard 1/N inverse normalization
+    fn normalize_inverse(&self, buffer: &mut [Complex<f32>]) {
+        let scale = self.normalization_factor();
+        for c in buffer.iter_mut() {
+            c.re *= scale;
+            c.im *= scale;
+        }
+    }
+
+    /// Perform 3D FFT by applying 1D FFT along each axis
+    ///
+    /// `buffer` may hold several volumes back to back; each is transformed
+    /// independently, sharing one plan lookup and one set of scratch buffers.
+    fn fft_3d(&mut self, buffer: &mut [Complex<f32>], forward: bool) -> Result<(), FftError> {
+        self.fft_3d_with_progress(buffer, forward, None)
+    }
+
+    /// Apply unscaled 1D FFTs along one axis of `buffer`
+    fn fft_axis(&mut self, buffer: &mut [Complex<f32>], axis: usize, forward: bool) -> Result<(), FftError> {
+        let (fft_forward, fft_inverse) = self.plan_cache.borrow_mut().get_plans(self.dims[axis])?;
+        let plan = if forward { fft_forward } else { fft_inverse };
+
+        let zero = Complex::new(0.0f32, 0.0f32);
+        let mut scratch = try_alloc(plan.get_inplace_scratch_len(), zero, self.size)?;
+        let mut transposed = try_alloc(self.total_size, zero, self.size)?;
+        transform_axis(buffer, self.dims, axis, &plan, &mut scratch, &mut transposed, &|_, _| {});
+        Ok(())
+    }
+
+    /// Perform 3D FFT, calling `progress` with the completed fraction after
+    /// each axis pass (see `transform_axes`)
+    ///
+    /// The clock is only read, to update `last_transform_ms`, when `progress`
+    /// is given or timing recording is enabled.
+    fn fft_3d_with_progress(
+        &mut self,
+        buffer: &mut [Complex<f32>],
+        forward: bool,
+        progress: Option<&mut dyn FnMut(f64)>,
+    ) -> Result<(), FftError> {
+        let timed = progress.is_some() || self.record_timing;
+        let start_ms = if timed { now_ms() } else { 0.0 };
+        let mut no_progress = |_| {};
+        let progress = progress.unwrap_or(&mut no_progress);
+
+        // One plan per axis; the cache shares plans between equal lengths
+        let mut plans = Vec::with_capacity(3);
+        for n in self.dims {
+            let (fft_forward, fft_inverse) = self.plan_cache.borrow_mut().get_plans(n)?;
+            plans.push(if forward { fft_forward } else { fft_inverse });
+        }
+
+        // Per-pass scale for the orthonormal-per-axis convention
+        let norm_mode = self.norm_mode;
+        let scale_pass = |data: &mut [Complex<f32>], n: usize| {
+            if norm_mode == NormMode::OrthonormalPerAxis {
+                let scale = 1.0 / (n as f32).sqrt();
+                data.iter_mut().for_each(|c| *c *= scale);
+            }
+        };
+        transform_axes(buffer, self.dims, [&plans[0], &plans[1], &plans[2]], scale_pass, progress)?;
+
+        // Conventions that scale the forward direction once, after all passes
+        if forward && matches!(self.norm_mode, NormMode::Forward | NormMode::Ortho) {
+            let scale = self.forward_scale();
+            buffer.iter_mut().for_each(|c| *c *= scale);
+        }
+
+        if timed {
+            self.last_transform_ms = now_ms() - start_ms;
+        }
+        Ok(())
+    }
+}
+
+/// Native (non-wasm-bindgen) API
+impl FFT3D {
+    /// Perform forward 3D FFT from one complex slice into another
+    ///
+    /// The zero-overhead primitive for native callers: no interleaving
+    /// conversion and no allocation of the complex buffer.
+    ///
+    /// # Arguments
+    /// * `input` - Complex input (length must be size^3)
+    /// * `output` - Destination for the spectrum (length must be size^3)
+    pub fn forward_into_complex(&mut self, input: &[Complex<f32>], output: &mut [Complex<f32>]) -> Result<(), FftError> {
+        for len in [input.len(), output.len()] {
+            if len != self.total_size {
+                return Err(FftError::LengthMismatch {
+                    expected: self.total_size,
+                    got: len,
+                });
+            }
+        }
+
+        output.copy_from_slice(input);
+        self.fft_3d(output, true)
+    }
+
+    /// Check that `verify_parseval` reports a relative error below `tolerance`
+    ///
+    /// Returns the input validation error unchanged, or `FftError::InvalidInput`
+    /// if the error is `tolerance` or more, so test suites can `unwrap` or assert
+    /// on the result.
+    pub fn check_parseval(&mut self, input_real: &[f32], input_imag: &[f32], tolerance: f32) -> Result<(), FftError> {
+        let error = self.parseval_error(input_real, input_imag)?;
+        if error.is_nan() || error >= tolerance {
+            return Err(FftError::InvalidInput {
+                reason: format!("Parseval relative error {} exceeds tolerance {}", error, tolerance),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Write complex values to `dst` as interleaved real/imag pairs
+fn write_interleaved(buffer: &[Complex<f32>], dst: &mut [f32]) {
+    for (out, c) in dst.chunks_exact_mut(2).zip(buffer.iter()) {
+        out[0] = c.re;
+        out[1] = c.im;
+    }
+}
+
+/// Error returned when an allocation for a transform of `size` fails
+fn out_of_memory(size: usize) -> FftError {
+    FftError::OutOfMemory { size }
+}
+
+/// Allocate `len` copies of `value`, returning an error instead of aborting when
+/// memory is exhausted
+fn try_alloc<T: Clone>(len: usize, value: T, size: usize) -> Result<Vec<T>, FftError> {
+    let mut buffer = Vec::new();
+    buffer.try_reserve_exact(len).map_err(|_| out_of_memory(size))?;
+    buffer.resize(len, value);
+    Ok(buffer)
+}
+
+/// Copy `data` into a new vector, returning an error instead of aborting when
+/// memory is exhausted
+fn try_copy<T: Copy>(data: &[T], size: usize) -> Result<Vec<T>, FftError> {
+    let mut buffer = Vec::new();
+    buffer.try_reserve_exact(data.len()).map_err(|_| out_of_memory(size))?;
+    buffer.extend_from_slice(data);
+    Ok(buffer)
+}
+
+/// Apply 1D FFTs along the X, Y and Z axes of every volume in `buffer`
+///
+/// Shared by `FFT3D` and `FFT3D64`. `scale_pass` is called on each block of
+/// transformed rows together with that axis length, for per-pass normalization.
+/// `progress` receives the completed fraction after the X pass (1/3), the Y
+/// pass (2/3) and the Z pass of each volume (reaching 1.0 after the last).
+fn transform_axes<T: FftNum>(
+    buffer: &mut [Complex<T>],
+    dims: [usize; 3],
+    plans: [&Arc<dyn Fft<T>>; 3],
+    scale_pass: impl Fn(&mut [Complex<T>], usize),
+    progress: &mut dyn FnMut(f64),
+) -> Result<(), FftError> {
+    let volume_size = dims.iter().product();
+
+    // Allocate scratch and transpose buffers once
+    let zero = Complex::new(T::zero(), T::zero());
+    let scratch_len = plans.iter().map(|p| p.get_inplace_scratch_len()).max().unwrap_or(0);
+    let mut scratch = try_alloc(scratch_len, zero, dims[0])?;
+    let mut transposed = try_alloc(volume_size, zero, dims[0])?;
+
+    transform_axis(buffer, dims, 0, plans[0], &mut scratch, &mut transposed, &scale_pass);
+    progress(1.0 / 3.0);
+    transform_axis(buffer, dims, 1, plans[1], &mut scratch, &mut transposed, &scale_pass);
+    progress(2.0 / 3.0);
+
+    let volumes = buffer.len() / volume_size;
+    for (v, volume) in buffer.chunks_exact_mut(volume_size).enumerate() {
+        transform_axis(volume, dims, 2, plans[2], &mut scratch, &mut transposed, &scale_pass);
+        progress((2.0 + (v + 1) as f64 / volumes as f64) / 3.0);
+    }
+    Ok(())
+}
+
+/// Apply 1D FFTs along one axis of every volume in `buffer`
+///
+/// `scratch` must hold the plan's in-place scratch length and `transposed` one
+/// volume. `scale_pass` is called as in `transform_axes`.
+fn transform_axis<T: FftNum>(
+    buffer: &mut [Complex<T>],
+    dims: [usize; 3],
+    axis: usize,
+    plan: &Arc<dyn Fft<T>>,
+    scratch: &mut [Complex<T>],
+    transposed: &mut [Complex<T>],
+    scale_pass: &impl Fn(&mut [Complex<T>], usize),
+) {
+    let [nx, ny, nz] = dims;
+    let plane_size = nx * ny;
+    match axis {
+        // X rows are already contiguous
+        0 => {
+            plan.process_with_scratch(buffer, scratch);
+            scale_pass(buffer, nx);
+        }
+        // Transpose each XY plane so Y columns become contiguous rows,
+        // transform, and transpose back
+        1 => {
+            for plane in buffer.chunks_exact_mut(plane_size) {
+                let rows = &mut transposed[..plane_size];
+                transpose::transpose(plane, rows, nx, ny);
+                plan.process_with_scratch(rows, scratch);
+                scale_pass(rows, ny);
+                transpose::transpose(rows, plane, ny, nx);
+            }
+        }
+        // Treat each volume as an nz x (nx*ny) matrix of Z rows by XY columns
+        // and transpose it the same way
+        _ => {
+            for volume in buffer.chunks_exact_mut(plane_size * nz) {
+                transpose::transpose(volume, transposed, plane_size, nz);
+                plan.process_with_scratch(transposed, scratch);
+                scale_pass(transposed, nz);
+                transpose::transpose(transposed, volume, nz, plane_size);
+            }
+        }
+    }
+}
+
+/// Validate a quantization step, requiring `step > 0`
+fn validate_step(step: f32) -> Result<(), FftError> {
+    if step.is_nan() || step <= 0.0 {
+        return Err(FftError::InvalidArgument {
+            name: "step",
+            reason: format!("{}. Expected > 0", step),
+        });
+    }
+    Ok(())
+}
+
+/// Validate radial band edges, requiring `0 <= low <= high <= 1`
+fn validate_band(low: f32, high: f32) -> Result<(), FftError> {
+    if !(0.0..=1.0).contains(&low) || !(low..=1.0).contains(&high) {
+        return Err(FftError::InvalidArgument {
+            name: "band",
+            reason: format!("[{}, {}]. Expected 0 <= low <= high <= 1", low, high),
+        });
+    }
+    Ok(())
+}
+
+/// Call a progress callback with `fraction`, remembering the first exception
+/// it throws and skipping further calls after one
+fn report_progress(callback: Option<&js_sys::Function>, fraction: f64, error: &mut Option<JsValue>) {
+    if let (Some(callback), None) = (callback, error.as_ref()) {
+        if let Err(e) = callback.call1(&JsValue::NULL, &JsValue::from_f64(fraction)) {
+            *error = Some(e);
+        }
+    }
+}
+
+/// Current time in milliseconds, from `performance.now()` when available
+#[cfg(target_arch = "wasm32")]
+fn now_ms() -> f64 {
+    let performance = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("performance"))
+        .ok()
+        .filter(|p| !p.is_undefined());
+    performance
+        .and_then(|p| {
+            js_sys::Reflect::get(&p, &JsValue::from_str("now"))
+                .ok()
+                .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+                .and_then(|f| f.call0(&p).ok())
+                .and_then(|t| t.as_f64())
+        })
+        .unwrap_or_else(js_sys::Date::now)
+}
+
+/// Current time in milliseconds since the first call
+#[cfg(not(target_arch = "wasm32"))]
+fn now_ms() -> f64 {
+    static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    START.get_or_init(std::time::Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
+/// Validate that a cube size is supported
+fn validate_size(size: usize) -> Result<(), FftError> {
+    if !is_supported_size(size) {
+        return Err(FftError::UnsupportedSize { size });
+    }
+    Ok(())
+}
+
+/// Number of voxels in a `size^3` cube, failing instead of overflowing
+fn cube_volume(size: usize) -> Result<usize, FftError> {
+    size.checked_mul(size)
+        .and_then(|square| square.checked_mul(size))
+        .ok_or_else(|| volume_overflow(size))
+}
+
+/// Validate that a `size^3` region at `origin` fits inside a `parent_size^3` grid
+fn validate_region(origin: [usize; 3], size: usize, parent_size: usize) -> Result<(), FftError> {
+    // Compare against `parent_size - size` so huge origins cannot wrap around
+    if size > parent_size || origin.iter().any(|&o| o > parent_size - size) {
+        return Err(FftError::RegionOutOfBounds {
+            origin,
+            size,
+            parent_size,
+        });
+    }
+    Ok(())
+}
+
+/// Error for a cube whose element count does not fit in `usize`
+fn volume_overflow(size: usize) -> FftError {
+    FftError::InvalidArgument {
+        name: "size",
+        reason: format!("{}^3 values exceed the addressable length", size),
+    }
+}
+
+/// Whether `size` is a power of two between `MIN_SIZE` and `MAX_SIZE`
+fn is_supported_size(size: usize) -> bool {
+    size.is_power_of_two() && (MIN_SIZE..=MAX_SIZE).contains(&size)
+}
+
+/// Supported cube sizes in increasing order
+fn supported_size_iter() -> impl Iterator<Item = usize> {
+    (MIN_SIZE.trailing_zeros()..=MAX_SIZE.trailing_zeros()).map(|p| 1 << p)
+}
+
+/// Approximate bytes held by the forward and inverse plans for one size
+fn estimated_plan_bytes(size: usize) -> usize {
+    // Each power-of-two plan stores about one twiddle factor per element
+    2 * size * std::mem::size_of::<Complex<f32>>()
+}
+
+/// Signed frequency index of bin `k` on an axis of length `n`
+/// Bins above n/2 wrap around to negative frequencies
+fn signed_frequency(k: usize, n: usize) -> f32 {
+    if k <= n / 2 {
+        k as f32
+    } else {
+        k as f32 - n as f32
+    }
+}
+
+/// Bin mapping for resampling one axis from length `n` to length `m`
+///
+/// Each entry is `(old_bin, new_bin, weight)`. Shared frequencies keep their
+/// sign; the Nyquist bin of the shorter axis is split (upsampling) or folded
+/// (downsampling) so a real field stays real.
+fn resample_axis_map(n: usize, m: usize) -> Vec<(usize, usize, f32)> {
+    let half = n.min(m) / 2;
+    let mut map = Vec::with_capacity(n.min(m) + 1);
+    for k in 0..half {
+        map.push((k, k, 1.0));
+        if k > 0 {
+            map.push((n - k, m - k, 1.0));
+        }
+    }
+    match n.cmp(&m) {
+        std::cmp::Ordering::Equal => map.push((half, half, 1.0)),
+        std::cmp::Ordering::Less => {
+            map.push((half, half, 0.5));
+            map.push((half, m - half, 0.5));
+        }
+        std::cmp::Ordering::Greater => {
+            map.push((half, half, 1.0));
+            map.push((n - half, half, 1.0));
+        }
+    }
+    map
+}
+
+/// Average a cube of values over shells of equal radius from the origin
+///
+/// Values are in standard (non-shifted) order, so indices above n/2 wrap to
+/// negative offsets. Each value goes to the shell of its rounded radius and
+/// shells beyond n/2 are dropped.
+///
+/// # Returns
+/// * Average per integer radius, length n/2 + 1
+fn radial_profile(values: &[f32], n: usize) -> Vec<f32> {
+    let bins = n / 2 + 1;
+    let mut sums = vec![0.0f64; bins];
+    let mut counts = vec![0usize; bins];
+
+    for (i, &value) in values.iter().enumerate() {
+        let fx = signed_frequency(i % n, n);
+        let fy = signed_frequency((i / n) % n, n);
+        let fz = signed_frequency(i / (n * n), n);
+        let r = (fx * fx + fy * fy + fz * fz).sqrt().round() as usize;
+        if r < bins {
+            sums[r] += value as f64;
+            counts[r] += 1;
+        }
+    }
+
+    sums.iter()
+        .zip(counts.iter())
+        .map(|(&sum, &count)| if count > 0 { (sum / count as f64) as f32 } else { 0.0 })
+        .collect()
+}
+
+/// Hermite smoothstep: 0 below `low`, 1 above `high`, `3t² - 2t³` in between
+fn smoothstep(low: f32, high: f32, x: f32) -> f32 {
+    let t = ((x - low) / (high - low)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Raised-cosine step: 0 below `edge - width/2`, 1 above `edge + width/2`,
+/// `(1 - cos(πt)) / 2` in between (`width` must be > 0)
+fn raised_cosine_step(edge: f32, width: f32, x: f32) -> f32 {
+    let t = ((x - edge) / width + 0.5).clamp(0.0, 1.0);
+    0.5 - 0.5 * (std::f32::consts::PI * t).cos()
+}
+
+/// Validate a filter transition width, requiring `width >= 0` and the
+/// transition bands around the edges `low..=high` to stay within [0, 1]
+fn validate_transition(low: f32, high: f32, width: f32) -> Result<(), FftError> {
+    if width.is_nan() || width < 0.0 {
+        return Err(FftError::InvalidArgument {
+            name: "transition width",
+            reason: format!("{}. Expected >= 0", width),
+        });
+    }
+    if width > 0.0 && (low - width / 2.0 < 0.0 || high + width / 2.0 > 1.0) {
+        return Err(FftError::InvalidArgument {
+            name: "transition width",
+            reason: format!(
+                "{}. The transition around [{}, {}] must stay within [0, 1]",
+                width, low, high
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Validate an axis index
+fn validate_axis(axis: usize) -> Result<(), FftError> {
+    if axis > 2 {
+        return Err(FftError::InvalidArgument {
+            name: "axis",
+            reason: format!("{}. Expected 0 (X), 1 (Y) or 2 (Z)", axis),
+        });
+    }
+    Ok(())
+}
+
+/// Double-precision 3D FFT Transformer
+/// Performs forward and inverse 3D FFT transformations on `f64` data, for
+/// workflows that accumulate rounding error over many iterated cycles
+#[wasm_bindgen]
+pub struct FFT3D64 {
+    size: usize,
+    total_size: usize,
+    fft_forward: Arc<dyn Fft<f64>>,
+    fft_inverse: Arc<dyn Fft<f64>>,
+}
+
+#[wasm_bindgen]
+impl FFT3D64 {
+    /// Create a new double-precision 3D FFT transformer
+    ///
+    /// # Arguments
+    /// * `size` - Cube dimension (a power of two from 2 to 256)
+    #[wasm_bindgen(constructor)]
+    pub fn new(size: usize) -> Result<FFT3D64, JsValue> {
+        validate_size(size)?;
+        let mut planner = FftPlanner::<f64>::new();
+        Ok(FFT3D64 {
+            size,
+            total_size: size * size * size,
+            fft_forward: planner.plan_fft_forward(size),
+            fft_inverse: planner.plan_fft_inverse(size),
+        })
+    }
+
+    /// Get the cube size
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the total number of elements (size^3)
+    #[wasm_bindgen(getter)]
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    /// Perform forward 3D FFT (space -> frequency)
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^3)
+    /// * `input_imag` - Imaginary parts of input (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float64Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward(&self, input_real: &[f64], input_imag: &[f64]) -> Result<Vec<f64>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, true)?;
+        Ok(self.interleave(&buffer)?)
+    }
+
+    /// Perform forward 3D FFT on interleaved input (space -> frequency)
+    ///
+    /// The f64 counterpart of `FFT3D::forward_interleaved`, for data that already
+    /// arrives interleaved from a double-precision backend.
+    ///
+    /// # Arguments
+    /// * `data` - Interleaved real/imag input (length must be 2 * size^3)
+    ///
+    /// # Returns
+    /// * `Float64Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn forward_f64_interleaved(&self, data: &[f64]) -> Result<Vec<f64>, JsValue> {
+        if data.len() != 2 * self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: 2 * self.total_size,
+                got: data.len(),
+            }
+            .into());
+        }
+
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(self.total_size)
+            .map_err(|_| out_of_memory(self.size))?;
+        buffer.extend(data.chunks_exact(2).map(|c| Complex::new(c[0], c[1])));
+        self.fft_3d(&mut buffer, true)?;
+        Ok(self.interleave(&buffer)?)
+    }
+
+    /// Perform inverse 3D FFT (frequency -> space), scaled by `1 / size^3`
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^3)
+    /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float64Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn inverse(&self, input_real: &[f64], input_imag: &[f64]) -> Result<Vec<f64>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_3d(&mut buffer, false)?;
+        let scale = 1.0 / self.total_size as f64;
+        buffer.iter_mut().for_each(|c| *c *= scale);
+        Ok(self.interleave(&buffer)?)
+    }
+
+    /// Validate input array lengths
+    fn validate_input(&self, input_real: &[f64], input_imag: &[f64]) -> Result<(), FftError> {
+        for input in [input_real, input_imag] {
+            if input.len() != self.total_size {
+                return Err(FftError::LengthMismatch {
+                    expected: self.total_size,
+                    got: input.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Build a complex buffer from separate real and imaginary arrays
+    fn complex_buffer(&self, input_real: &[f64], input_imag: &[f64]) -> Result<Vec<Complex<f64>>, FftError> {
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(input_real.len())
+            .map_err(|_| out_of_memory(self.size))?;
+        buffer.extend(
+            input_real
+                .iter()
+                .zip(input_imag.iter())
+                .map(|(&re, &im)| Complex::new(re, im)),
+        );
+        Ok(buffer)
+    }
+
+    /// Convert a complex buffer to interleaved real/imag output
+    fn interleave(&self, buffer: &[Complex<f64>]) -> Result<Vec<f64>, FftError> {
+        let mut output = try_alloc(2 * buffer.len(), 0.0f64, self.size)?;
+        for (out, c) in output.chunks_exact_mut(2).zip(buffer.iter()) {
+            out[0] = c.re;
+            out[1] = c.im;
+        }
+        Ok(output)
+    }
+
+    /// Perform 3D FFT by applying 1D FFT along each axis
+    fn fft_3d(&self, buffer: &mut [Complex<f64>], forward: bool) -> Result<(), FftError> {
+        let plan = if forward { &self.fft_forward } else { &self.fft_inverse };
+        transform_axes(buffer, [self.size; 3], [plan, plan, plan], |_, _| {}, &mut |_| {})
+    }
+}
+
+/// 2D FFT Transformer
+/// Performs forward and inverse 2D FFT transformations on square slices
+#[wasm_bindgen]
+pub struct FFT2D {
+    size: usize,
+    total_size: usize,
+    /// Plan cache, possibly shared with a 3D transformer via `share_cache_with`
+    plan_cache: Rc<RefCell<FFTPlanCache>>,
+    /// Where the `1 / size^2` scale is applied
+    norm_mode: NormMode,
+}
+
+#[wasm_bindgen]
+impl FFT2D {
+    /// Create a new 2D FFT transformer
+    ///
+    /// The transformer starts with its own plan cache; call `share_cache_with`
+    /// to reuse the 1D plans of an `FFT3D`.
+    ///
+    /// # Arguments
+    /// * `size` - Side length (a power of two from 2 to 256)
+    ///
+    /// # Returns
+    /// * `Result<FFT2D, JsValue>` - The transformer or an error
+    #[wasm_bindgen(constructor)]
+    pub fn new(size: usize) -> Result<FFT2D, JsValue> {
+        validate_size(size)?;
+        Ok(FFT2D {
+            size,
+            total_size: size * size,
+            plan_cache: Rc::new(RefCell::new(FFTPlanCache::new())),
+            norm_mode: NormMode::Backward,
+        })
+    }
+
+    /// Use the same plan cache as a 3D transformer
+    ///
+    /// 2D and 3D transforms of the same size then share their 1D plans.
+    ///
+    /// # Arguments
+    /// * `other` - Transformer whose cache to share
+    #[wasm_bindgen]
+    pub fn share_cache_with(&mut self, other: &FFT3D) {
+        self.plan_cache = Rc::clone(&other.plan_cache);
+    }
+
+    /// Get the side length
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the total number of elements (size^2)
+    #[wasm_bindgen(getter)]
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    /// Get the normalization convention
+    #[wasm_bindgen(getter)]
+    pub fn norm_mode(&self) -> NormMode {
+        self.norm_mode
+    }
+
+    /// Set the normalization convention
+    ///
+    /// Same conventions as `FFT3D::set_norm`, splitting `1 / size^2` between
+    /// the two directions; `NormMode::OrthonormalPerAxis` scales each of the
+    /// two 1D passes by `1 / sqrt(size)`.
+    ///
+    /// # Arguments
+    /// * `mode` - Normalization convention
+    #[wasm_bindgen]
+    pub fn set_norm(&mut self, mode: NormMode) {
+        self.norm_mode = mode;
+    }
+
+    /// Perform forward 2D FFT (space -> frequency)
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of input (length must be size^2)
+    /// * `input_imag` - Imaginary parts of input (length must be size^2)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^2),
+    ///   unscaled under the default `NormMode::Backward`
+    #[wasm_bindgen]
+    pub fn forward(&self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_2d(&mut buffer, true)?;
+        Ok(self.interleave(&buffer)?)
+    }
+
+    /// Perform inverse 2D FFT (frequency -> space)
+    ///
+    /// Scaled by `1 / size^2` under the default `NormMode::Backward`.
+    ///
+    /// # Arguments
+    /// * `input_real` - Real parts of frequency coefficients (length must be size^2)
+    /// * `input_imag` - Imaginary parts of frequency coefficients (length must be size^2)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^2)
+    #[wasm_bindgen]
+    pub fn inverse(&self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input_real, input_imag)?;
+        let mut buffer = self.complex_buffer(input_real, input_imag)?;
+        self.fft_2d(&mut buffer, false)?;
+        let scale = match self.norm_mode {
+            NormMode::Backward => 1.0 / self.total_size as f32,
+            NormMode::Forward | NormMode::OrthonormalPerAxis => 1.0,
+            NormMode::Ortho => 1.0 / self.size as f32,
+        };
+        buffer.iter_mut().for_each(|c| *c *= scale);
+        Ok(self.interleave(&buffer)?)
+    }
+
+    /// Validate input array lengths
+    fn validate_input(&self, input_real: &[f32], input_imag: &[f32]) -> Result<(), FftError> {
+        for input in [input_real, input_imag] {
+            if input.len() != self.total_size {
+                return Err(FftError::LengthMismatch {
+                    expected: self.total_size,
+                    got: input.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Build a complex buffer from separate real and imaginary arrays
+    fn complex_buffer(&self, input_real: &[f32], input_imag: &[f32]) -> Result<Vec<Complex<f32>>, FftError> {
+        let mut buffer = Vec::new();
+        buffer
+            .try_reserve_exact(input_real.len())
+            .map_err(|_| out_of_memory(self.size))?;
+        buffer.extend(
+            input_real
+                .iter()
+                .zip(input_imag.iter())
+                .map(|(&re, &im)| Complex::new(re, im)),
+        );
+        Ok(buffer)
+    }
+
+    /// Convert a complex buffer to interleaved real/imag output
+    fn interleave(&self, buffer: &[Complex<f32>]) -> Result<Vec<f32>, FftError> {
+        let mut output = try_alloc(2 * buffer.len(), 0.0f32, self.size)?;
+        write_interleaved(buffer, &mut output);
+        Ok(output)
+    }
+
+    /// Perform 2D FFT by applying 1D FFT along X, then along Y
+    fn fft_2d(&self, buffer: &mut [Complex<f32>], forward: bool) -> Result<(), FftError> {
+        let (fft_forward, fft_inverse) = self.plan_cache.borrow_mut().get_plans(self.size)?;
+        let fft = if forward { fft_forward } else { fft_inverse };
+
+        let zero = Complex::new(0.0f32, 0.0f32);
+        let mut scratch = try_alloc(fft.get_inplace_scratch_len(), zero, self.size)?;
+        let mut transposed = try_alloc(self.total_size, zero, self.size)?;
+
+        // Per-pass scale for the orthonormal-per-axis convention
+        let scale_pass = |data: &mut [Complex<f32>]| {
+            if self.norm_mode == NormMode::OrthonormalPerAxis {
+                let scale = 1.0 / (self.size as f32).sqrt();
+                data.iter_mut().for_each(|c| *c *= scale);
+            }
+        };
+
+        // Transform along X-axis (rows are already contiguous)
+        fft.process_with_scratch(buffer, &mut scratch);
+        scale_pass(buffer);
+
+        // Transform along Y-axis: transpose so columns become rows and back
+        transpose::transpose(buffer, &mut transposed, self.size, self.size);
+        fft.process_with_scratch(&mut transposed, &mut scratch);
+        scale_pass(&mut transposed);
+        transpose::transpose(&transposed, buffer, self.size, self.size);
+
+        // Conventions that scale the forward direction once, after both passes
+        if forward {
+            let scale = match self.norm_mode {
+                NormMode::Forward => 1.0 / self.total_size as f32,
+                NormMode::Ortho => 1.0 / self.size as f32,
+                NormMode::Backward | NormMode::OrthonormalPerAxis => 1.0,
+            };
+            buffer.iter_mut().for_each(|c| *c *= scale);
+        }
+        Ok(())
+    }
+}
+
+/// 3D DCT Transformer
+/// Performs separable 3D DCT-II (forward) and DCT-III (inverse) transforms on
+/// real data, which compact the energy of smooth fields better than the DFT
+///
+/// Each 1D transform uses the even-extension trick: a length-N line is
+/// mirrored to length 2N, transformed with a complex FFT, and rotated by the
+/// half-sample twiddle `exp(-iπk/(2N))`.
+#[wasm_bindgen]
+pub struct DCT3D {
+    size: usize,
+    total_size: usize,
+    /// Length-2N plans for the even extension
+    fft_forward: Arc<dyn Fft<f32>>,
+    fft_inverse: Arc<dyn Fft<f32>>,
+    /// `exp(-iπk/(2N))` for `k` in `0..N`
+    twiddles: Vec<Complex<f32>>,
+}
+
+#[wasm_bindgen]
+impl DCT3D {
+    /// Create a new 3D DCT transformer
+    ///
+    /// # Arguments
+    /// * `size` - Cube dimension (a power of two from 2 to 256)
+    #[wasm_bindgen(constructor)]
+    pub fn new(size: usize) -> Result<DCT3D, JsValue> {
+        validate_size(size)?;
+        let mut planner = FftPlanner::new();
+        let twiddles = (0..size)
+            .map(|k| Complex::from_polar(1.0, -std::f32::consts::PI * k as f32 / (2 * size) as f32))
+            .collect();
+        Ok(DCT3D {
+            size,
+            total_size: size * size * size,
+            fft_forward: planner.plan_fft_forward(2 * size),
+            fft_inverse: planner.plan_fft_inverse(2 * size),
+            twiddles,
+        })
+    }
+
+    /// Get the cube size
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the total number of elements (size^3)
+    #[wasm_bindgen(getter)]
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    /// Perform forward 3D DCT-II
+    ///
+    /// Unnormalized: `X[k] = Σ x[n] cos(π(n + 1/2)k / N)` along each axis, so a
+    /// constant field `c` maps to `c · size^3` at the DC coefficient.
+    ///
+    /// # Arguments
+    /// * `input` - Real field (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Real DCT coefficients (length = size^3)
+    #[wasm_bindgen]
+    pub fn forward(&self, input: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input)?;
+        let mut output = try_copy(input, self.size)?;
+        self.apply_separable(&mut output, |line, work, scratch| self.dct2_line(line, work, scratch))?;
+        Ok(output)
+    }
+
+    /// Perform inverse 3D DCT (DCT-III scaled by `1 / size` per axis)
+    ///
+    /// # Arguments
+    /// * `input` - Real DCT coefficients (length must be size^3)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Real field (length = size^3)
+    #[wasm_bindgen]
+    pub fn inverse(&self, input: &[f32]) -> Result<Vec<f32>, JsValue> {
+        self.validate_input(input)?;
+        let mut output = try_copy(input, self.size)?;
+        self.apply_separable(&mut output, |line, work, scratch| self.dct3_line(line, work, scratch))?;
+        Ok(output)
+    }
+
+    /// Validate input array length
+    fn validate_input(&self, input: &[f32]) -> Result<(), FftError> {
+        if input.len() != self.total_size {
+            return Err(FftError::LengthMismatch {
+                expected: self.total_size,
+                got: input.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Apply a 1D line transform along X, Y and Z, transposing so every line
+    /// is contiguous
+    fn apply_separable(
+        &self,
+        data: &mut [f32],
+        line_op: impl Fn(&mut [f32], &mut [Complex<f32>], &mut [Complex<f32>]),
+    ) -> Result<(), FftError> {
+        let n = self.size;
+        let zero = Complex::new(0.0f32, 0.0f32);
+        let scratch_len = self
+            .fft_forward
+            .get_inplace_scratch_len()
+            .max(self.fft_inverse.get_inplace_scratch_len());
+        let mut scratch = try_alloc(scratch_len, zero, n)?;
+        let mut work = try_alloc(2 * n, zero, n)?;
+        let mut transposed = try_alloc(self.total_size, 0.0f32, n)?;
+        let mut lines = |rows: &mut [f32]| {
+            for line in rows.chunks_exact_mut(n) {
+                line_op(line, &mut work, &mut scratch);
+            }
+        };
+
+        // X lines are contiguous
+        lines(data);
+
+        // Y lines: transpose each XY plane
+        for plane in data.chunks_exact_mut(n * n) {
+            let rows = &mut transposed[..n * n];
+            transpose::transpose(plane, rows, n, n);
+            lines(rows);
+            transpose::transpose(rows, plane, n, n);
+        }
+
+        // Z lines: transpose the volume as an n x (n*n) matrix
+        transpose::transpose(data, &mut transposed, n * n, n);
+        lines(&mut transposed);
+        transpose::transpose(&transposed, data, n, n * n);
+        Ok(())
+    }
+
+    /// Unnormalized DCT-II of one line via a length-2N FFT of its even extension
+    fn dct2_line(&self, line: &mut [f32], work: &mut [Complex<f32>], scratch: &mut [Complex<f32>]) {
+        let n = line.len();
+        for (i, &x) in line.iter().enumerate() {
+            work[i] = Complex::new(x, 0.0);
+            work[2 * n - 1 - i] = Complex::new(x, 0.0);
+        }
+        self.fft_forward.process_with_scratch(work, scratch);
+        for (k, out) in line.iter_mut().enumerate() {
+            *out = (self.twiddles[k] * work[k]).re / 2.0;
+        }
+    }
+
+    /// Inverse of `dct2_line` (DCT-III scaled by `1 / N`) via a length-2N
+    /// inverse FFT of the Hermitian extension of the rotated coefficients
+    fn dct3_line(&self, line: &mut [f32], work: &mut [Complex<f32>], scratch: &mut [Complex<f32>]) {
+        let n = line.len();
+        work[0] = Complex::new(line[0], 0.0);
+        work[n] = Complex::new(0.0, 0.0);
+        for k in 1..n {
+            let c = line[k] * self.twiddles[k].conj();
+            work[k] = c;
+            work[2 * n - k] = c.conj();
+        }
+        self.fft_inverse.process_with_scratch(work, scratch);
+        let scale = 1.0 / n as f32;
+        for (out, c) in line.iter_mut().zip(work.iter()) {
+            *out = c.re * scale;
+        }
+    }
+}
+
+/// Complex Field
+/// Holds the real and imaginary parts of a field together so they cannot be swapped
+#[wasm_bindgen]
+pub struct ComplexField {
+    real: Vec<f32>,
+    imag: Vec<f32>,
+}
+
+#[wasm_bindgen]
+impl ComplexField {
+    /// Create a new complex field
+    ///
+    /// # Arguments
+    /// * `real` - Real parts (length must be size^3 for a supported size)
+    /// * `imag` - Imaginary parts (same length as `real`)
+    ///
+    /// # Returns
+    /// * `Result<ComplexField, JsValue>` - The field or an error
+    #[wasm_bindgen(constructor)]
+    pub fn new(real: &[f32], imag: &[f32]) -> Result<ComplexField, JsValue> {
+        if real.len() != imag.len() {
+            return Err(FftError::LengthMismatch {
+                expected: real.len(),
+                got: imag.len(),
+            }
+            .into());
+        }
+        if !supported_size_iter().any(|n| n * n * n == real.len()) {
+            return Err(FftError::InvalidLength {
+                got: real.len(),
+                expected: format!("size^3 for a power-of-two size from {} to {}", MIN_SIZE, MAX_SIZE),
+            }
+            .into());
+        }
+
+        Ok(ComplexField {
+            real: real.to_vec(),
+            imag: imag.to_vec(),
+        })
+    }
+
+    /// Get the real parts
+    #[wasm_bindgen(getter)]
+    pub fn real(&self) -> Vec<f32> {
+        self.real.clone()
+    }
+
+    /// Get the imaginary parts
+    #[wasm_bindgen(getter)]
+    pub fn imag(&self) -> Vec<f32> {
+        self.imag.clone()
+    }
+
+    /// Get the number of complex values
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.real.len()
+    }
+}
+
+/// Chunked Input
+/// Accumulates a real volume from several chunks, then runs a forward FFT on it
+#[wasm_bindgen]
+pub struct ChunkedInput {
+    fft: FFT3D,
+    data: Vec<f32>,
+}
+
+#[wasm_bindgen]
+impl ChunkedInput {
+    /// Create a new chunked input accumulator
+    ///
+    /// # Arguments
+    /// * `size` - Size of each dimension (a power of two from 2 to 256)
+    ///
+    /// # Returns
+    /// * `Result<ChunkedInput, JsValue>` - The accumulator or an error
+    #[wasm_bindgen(constructor)]
+    pub fn new(size: usize) -> Result<ChunkedInput, JsValue> {
+        let fft = FFT3D::new(size)?;
+        let data = Vec::with_capacity(fft.total_size());
+        Ok(ChunkedInput { fft, data })
+    }
+
+    /// Append a chunk of real values
+    ///
+    /// # Arguments
+    /// * `data` - Next chunk of the volume in linear (x fastest) order
+    #[wasm_bindgen]
+    pub fn push_chunk(&mut self, data: &[f32]) -> Result<(), JsValue> {
+        if self.data.len() + data.len() > self.fft.total_size() {
+            return Err(FftError::InvalidLength {
+                got: self.data.len() + data.len(),
+                expected: format!("at most {} values in total", self.fft.total_size()),
+            }
+            .into());
+        }
+        self.data.extend_from_slice(data);
+        Ok(())
+    }
+
+    /// Get the number of values collected so far
+    #[wasm_bindgen(getter)]
+    pub fn collected(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check whether the whole volume has been collected
+    #[wasm_bindgen(getter)]
+    pub fn complete(&self) -> bool {
+        self.data.len() == self.fft.total_size()
+    }
+
+    /// Discard the collected values to start a new volume
+    #[wasm_bindgen]
+    pub fn reset(&mut self) {
+        self.data.clear();
+    }
+
+    /// Perform forward 3D FFT on the collected volume (space -> frequency)
+    ///
+    /// # Returns
+    /// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3)
+    #[wasm_bindgen]
+    pub fn transform(&mut self) -> Result<Vec<f32>, JsValue> {
+        if !self.complete() {
+            return Err(FftError::LengthMismatch {
+                expected: self.fft.total_size(),
+                got: self.data.len(),
+            }
+            .into());
+        }
+        let imag = vec![0.0f32; self.data.len()];
+        self.fft.forward(&self.data, &imag)
+    }
+}
+
+/// FFT Result
+/// Spectrum returned by `FFT3D::forward_full` with derived quantities
+#[wasm_bindgen]
+pub struct FftResult {
+    spectrum: Vec<f32>,
+    energy: f32,
+    /// Computed on first access
+    magnitude: OnceCell<Vec<f32>>,
+}
+
+#[wasm_bindgen]
+impl FftResult {
+    /// Get the interleaved real/imag spectrum (eager)
+    #[wasm_bindgen(getter)]
+    pub fn spectrum(&self) -> Vec<f32> {
+        self.spectrum.clone()
+    }
+
+    /// Get the spectral energy `Σ|c|²` (eager, computed during the transform)
+    #[wasm_bindgen(getter)]
+    pub fn energy(&self) -> f32 {
+        self.energy
+    }
+
+    /// Get `|c|` per coefficient (lazy, computed once on first access)
+    #[wasm_bindgen(getter)]
+    pub fn magnitude(&self) -> Vec<f32> {
+        self.magnitude
+            .get_or_init(|| {
+                self.spectrum
+                    .chunks_exact(2)
+                    .map(|c| c[0].hypot(c[1]))
+                    .collect()
+            })
+            .clone()
+    }
+
+    /// Get a polar view of the spectrum with lazily computed magnitude and phase
+    ///
+    /// Magnitudes already computed by this result are carried over.
+    #[wasm_bindgen]
+    pub fn to_polar(&self) -> PolarFftResult {
+        let magnitude = OnceCell::new();
+        if let Some(values) = self.magnitude.get() {
+            let _ = magnitude.set(values.clone());
+        }
+        PolarFftResult {
+            spectrum: self.spectrum.clone(),
+            magnitude,
+            phase: OnceCell::new(),
+        }
+    }
+}
+
+/// Polar FFT Result
+/// Magnitude/phase view of an `FftResult`, for editing in polar space
+#[wasm_bindgen]
+pub struct PolarFftResult {
+    spectrum: Vec<f32>,
+    /// Computed on first access
+    magnitude: OnceCell<Vec<f32>>,
+    /// Computed on first access
+    phase: OnceCell<Vec<f32>>,
+}
+
+#[wasm_bindgen]
+impl PolarFftResult {
+    /// Get `|c|` per coefficient (lazy, computed once on first access)
+    #[wasm_bindgen(getter)]
+    pub fn magnitude(&self) -> Vec<f32> {
+        self.magnitude
+            .get_or_init(|| {
+                self.spectrum
+                    .chunks_exact(2)
+                    .map(|c| c[0].hypot(c[1]))
+                    .collect()
+            })
+            .clone()
+    }
+
+    /// Get `arg(c)` in radians per coefficient (lazy, computed once on first access)
+    #[wasm_bindgen(getter)]
+    pub fn phase(&self) -> Vec<f32> {
+        self.phase
+            .get_or_init(|| {
+                self.spectrum
+                    .chunks_exact(2)
+                    .map(|c| c[1].atan2(c[0]))
+                    .collect()
+            })
+            .clone()
+    }
+}
+
+/// Timed FFT Result
+/// Spectrum of `FFT3D::forward_timed` with the duration of each axis pass
+#[wasm_bindgen]
+pub struct TimedFftResult {
+    spectrum: Vec<f32>,
+    x_pass_ms: f64,
+    y_pass_ms: f64,
+    z_pass_ms: f64,
+}
+
+#[wasm_bindgen]
+impl TimedFftResult {
+    /// Get the interleaved real/imag spectrum
+    #[wasm_bindgen(getter)]
+    pub fn spectrum(&self) -> Vec<f32> {
+        self.spectrum.clone()
+    }
+
+    /// Get the duration of the X pass in milliseconds
+    #[wasm_bindgen(getter)]
+    pub fn x_pass_ms(&self) -> f64 {
+        self.x_pass_ms
+    }
+
+    /// Get the duration of the Y pass in milliseconds
+    #[wasm_bindgen(getter)]
+    pub fn y_pass_ms(&self) -> f64 {
+        self.y_pass_ms
+    }
+
+    /// Get the duration of the Z pass in milliseconds
+    #[wasm_bindgen(getter)]
+    pub fn z_pass_ms(&self) -> f64 {
+        self.z_pass_ms
+    }
+}
+
+/// Field Histogram
+/// Value distribution of a spatial field together with the range it covers
+#[wasm_bindgen]
+pub struct FieldHistogram {
+    counts: Vec<f32>,
+    min: f32,
+    max: f32,
+}
+
+#[wasm_bindgen]
+impl FieldHistogram {
+    /// Get the count per bin
+    #[wasm_bindgen(getter)]
+    pub fn counts(&self) -> Vec<f32> {
+        self.counts.clone()
+    }
+
+    /// Get the lower edge of the first bin (field minimum)
+    #[wasm_bindgen(getter)]
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Get the upper edge of the last bin (field maximum)
+    #[wasm_bindgen(getter)]
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+}
+
+/// Compute a histogram of the real parts of an interleaved spatial field
+///
+/// Bins evenly divide the field's own min..max range; the maximum falls in the
+/// last bin. A constant field puts every value in the first bin.
+///
+/// # Arguments
+/// * `data` - Interleaved inverse output (length 2 * size^3); imaginary parts are ignored
+/// * `size` - Size of each dimension
+/// * `num_bins` - Number of histogram bins (must be at least 1)
+///
+/// # Returns
+/// * `FieldHistogram` - Counts plus the min/max range they cover
+#[wasm_bindgen]
+pub fn spatial_histogram(data: &[f32], size: usize, num_bins: usize) -> Result<FieldHistogram, JsValue> {
+    let total_size = cube_volume(size)?;
+    if total_size == 0 {
+        return Err(FftError::EmptyInput.into());
+    }
+    let expected = total_size.checked_mul(2).ok_or_else(|| volume_overflow(size))?;
+    if data.len() != expected {
+        return Err(FftError::LengthMismatch {
+            expected,
+            got: data.len(),
+        }
+        .into());
+    }
+    if num_bins == 0 {
+        return Err(FftError::InvalidArgument {
+            name: "bin count",
+            reason: "must be at least 1".to_string(),
+        }
+        .into());
+    }
+
+    let (min, max) = data
+        .iter()
+        .step_by(2)
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+
+    let mut counts = vec![0.0f32; num_bins];
+    let range = max - min;
+    for &value in data.iter().step_by(2) {
+        let bin = if range > 0.0 {
+            (((value - min) / range * num_bins as f32) as usize).min(num_bins - 1)
+        } else {
+            0
+        };
+        counts[bin] += 1.0;
+    }
+
+    Ok(FieldHistogram { counts, min, max })
+}
+
+/// Compute RMS, peak and mean of the real parts of an interleaved field
+///
+/// Only the real components are used; imaginary parts are skipped.
+///
+/// # Arguments
+/// * `data` - Interleaved inverse output (length 2 * size^3)
+/// * `size` - Size of each dimension
+///
+/// # Returns
+/// * `Float32Array` - `[rms, peak, mean]`, where `peak` is the largest absolute value
+#[wasm_bindgen]
+pub fn field_stats(data: &[f32], size: usize) -> Result<Vec<f32>, JsValue> {
+    let total_size = cube_volume(size)?;
+    if total_size == 0 {
+        return Err(FftError::EmptyInput.into());
+    }
+    let expected = total_size.checked_mul(2).ok_or_else(|| volume_overflow(size))?;
+    if data.len() != expected {
+        return Err(FftError::LengthMismatch {
+            expected,
+            got: data.len(),
+        }
+        .into());
+    }
+
+    let mut sum = 0.0f64;
+    let mut sum_sq = 0.0f64;
+    let mut peak = 0.0f32;
+    for &value in data.iter().step_by(2) {
+        sum += value as f64;
+        sum_sq += (value as f64) * (value as f64);
+        peak = peak.max(value.abs());
+    }
+
+    let count = total_size as f64;
+    Ok(vec![(sum_sq / count).sqrt() as f32, peak, (sum / count) as f32])
+}
+
+/// Calculate total energy using Parseval's theorem
+/// E = sum(|coefficient|^2)
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients
+///
+/// # Returns
+/// * `f32` - Total energy
+#[wasm_bindgen]
+pub fn calculate_energy(coefficients_real: &[f32], coefficients_imag: &[f32]) -> Result<f32, JsValue> {
+    Ok(pair_energy(coefficients_real, coefficients_imag)?)
+}
+
+/// Shared implementation of `calculate_energy`
+fn pair_energy(coefficients_real: &[f32], coefficients_imag: &[f32]) -> Result<f32, FftError> {
+    validate_pair_length(coefficients_real, coefficients_imag, coefficients_real.len())?;
+
+    let energy: f32 = coefficients_real
+        .iter()
+        .zip(coefficients_imag.iter())
+        .map(|(&re, &im)| re * re + im * im)
+        .sum();
+
+    Ok(energy)
+}
+
+/// Calculate the magnitude of each interleaved complex value
+/// |c| = sqrt(re^2 + im^2)
+///
+/// # Arguments
+/// * `data` - Interleaved real/imag values (even length)
+///
+/// # Returns
+/// * `Float32Array` - One magnitude per complex value
+#[wasm_bindgen]
+pub fn magnitude(data: &[f32]) -> Result<Vec<f32>, JsValue> {
+    validate_even_length(data)?;
+    Ok(data.chunks_exact(2).map(|c| c[0].hypot(c[1])).collect())
+}
+
+/// Calculate the phase of each interleaved complex value
+/// arg(c) = atan2(im, re)
+///
+/// # Arguments
+/// * `data` - Interleaved real/imag values (even length)
+///
+/// # Returns
+/// * `Float32Array` - One phase in radians, in (-π, π], per complex value
+#[wasm_bindgen]
+pub fn phase(data: &[f32]) -> Result<Vec<f32>, JsValue> {
+    validate_even_length(data)?;
+    Ok(data.chunks_exact(2).map(|c| c[1].atan2(c[0])).collect())
+}
+
+/// Validate that separate real and imaginary arrays both hold `expected` values
+fn validate_pair_length(real: &[f32], imag: &[f32], expected: usize) -> Result<(), FftError> {
+    for len in [real.len(), imag.len()] {
+        if len != expected {
+            return Err(FftError::LengthMismatch { expected, got: len });
+        }
+    }
+    Ok(())
+}
+
+/// Validate that interleaved data holds whole complex values
+fn validate_even_length(data: &[f32]) -> Result<(), FftError> {
+    if !data.len().is_multiple_of(2) {
+        return Err(FftError::InvalidLength {
+            got: data.len(),
+            expected: "an even number of interleaved values".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Calculate the power of each FFT coefficient
+/// P = |coefficient|^2
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients
+///
+/// # Returns
+/// * `Float32Array` - `re² + im²` per coefficient
+#[wasm_bindgen]
+pub fn power_spectrum(coefficients_real: &[f32], coefficients_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+    if coefficients_real.len() != coefficients_imag.len() {
+        return Err(FftError::LengthMismatch {
+            expected: coefficients_real.len(),
+            got: coefficients_imag.len(),
+        }
+        .into());
+    }
+
+    Ok(coefficients_real
+        .iter()
+        .zip(coefficients_imag.iter())
+        .map(|(&re, &im)| re * re + im * im)
+        .collect())
+}
+
+/// Calculate the power of each FFT coefficient in decibels
+/// P_dB = 10 * log10(|coefficient|^2 + epsilon)
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients
+/// * `epsilon` - Floor added to the power so empty bins stay finite (e.g. 1e-12)
+///
+/// # Returns
+/// * `Float32Array` - Power in dB per coefficient
+#[wasm_bindgen]
+pub fn log_power_spectrum(
+    coefficients_real: &[f32],
+    coefficients_imag: &[f32],
+    epsilon: f32,
+) -> Result<Vec<f32>, JsValue> {
+    let mut power = power_spectrum(coefficients_real, coefficients_imag)?;
+    for p in power.iter_mut() {
+        *p = 10.0 * (*p + epsilon).log10();
+    }
+    Ok(power)
+}
+
+/// Compute the radially averaged power spectrum
+///
+/// The input is a full size^3 spectrum in standard (non-shifted) FFT order, so
+/// indices above size/2 are negative frequencies. Each coefficient's power goes
+/// to the bin of its rounded frequency magnitude; bins beyond size/2 (the
+/// corners of the cube) are dropped.
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients (length size^3)
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients (length size^3)
+///
+/// # Returns
+/// * `Float32Array` - Average power per integer radius, length size/2 + 1
+#[wasm_bindgen]
+pub fn radial_spectrum(coefficients_real: &[f32], coefficients_imag: &[f32]) -> Result<Vec<f32>, JsValue> {
+    let power = power_spectrum(coefficients_real, coefficients_imag)?;
+    let size = supported_size_iter()
+        .find(|&n| n * n * n == power.len())
+        .ok_or_else(|| {
+            JsValue::from(FftError::InvalidLength {
+                got: power.len(),
+                expected: format!("size^3 for a power-of-two size from {} to {}", MIN_SIZE, MAX_SIZE),
+            })
+        })?;
+
+    Ok(radial_profile(&power, size))
+}
+
+/// Calculate the energy of the difference between two spectra
+/// E = sum(|a - b|^2)
+///
+/// By Parseval's theorem this equals `N` times the squared spatial L2 distance
+/// between the two fields (with the default normalization), so iterative solvers
+/// can monitor convergence without an inverse transform.
+///
+/// # Arguments
+/// * `a` - First interleaved spectrum
+/// * `b` - Second interleaved spectrum (same length as `a`)
+///
+/// # Returns
+/// * `f32` - Energy of the difference
+#[wasm_bindgen]
+pub fn spectral_energy_difference(a: &[f32], b: &[f32]) -> Result<f32, JsValue> {
+    if a.len() != b.len() {
+        return Err(FftError::LengthMismatch {
+            expected: a.len(),
+            got: b.len(),
+        }
+        .into());
+    }
+    validate_even_length(a)?;
+
+    // Accumulate in f64 so small late-iteration differences are not swamped
+    let energy: f64 = a
+        .iter()
+        .zip(b.iter())
+        .map(|(&x, &y)| {
+            let d = (x - y) as f64;
+            d * d
+        })
+        .sum();
+
+    Ok(energy as f32)
+}
+
+/// Compute the magnitude spectrum normalized to the DC term
+///
+/// Every magnitude is divided by the magnitude of the DC coefficient (index 0),
+/// so the result is invariant to the overall amplitude of the field.
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients
+///
+/// # Returns
+/// * `Float32Array` - `|c| / |c[0]|` per coefficient; errors if the DC term is zero
+#[wasm_bindgen]
+pub fn normalized_magnitude_spectrum(
+    coefficients_real: &[f32],
+    coefficients_imag: &[f32],
+) -> Result<Vec<f32>, JsValue> {
+    if coefficients_real.len() != coefficients_imag.len() {
+        return Err(FftError::LengthMismatch {
+            expected: coefficients_real.len(),
+            got: coefficients_imag.len(),
+        }
+        .into());
+    }
+    if coefficients_real.is_empty() {
+        return Err(FftError::EmptyInput.into());
+    }
+
+    let dc = coefficients_real[0].hypot(coefficients_imag[0]);
+    if dc == 0.0 {
+        return Err(FftError::InvalidInput {
+            reason: "DC term is zero; spectrum cannot be normalized".to_string(),
+        }
+        .into());
+    }
+
+    let normalized: Vec<f32> = coefficients_real
+        .iter()
         .zip(coefficients_imag.iter())
-        .map(|(&re, &im)| re * re + im * im)
+        .map(|(&re, &im)| re.hypot(im) / dc)
+        .collect();
+
+    Ok(normalized)
+}
+
+/// Blend several spectra into their weighted sum
+///
+/// # Arguments
+/// * `spectra` - `count` interleaved spectra of equal length, concatenated
+/// * `weights` - One weight per spectrum (length must be `count`)
+/// * `count` - Number of spectra
+///
+/// # Returns
+/// * `Float32Array` - Interleaved weighted sum (length = `spectra.len() / count`)
+#[wasm_bindgen]
+pub fn blend_spectra(spectra: &[f32], weights: &[f32], count: usize) -> Result<Vec<f32>, JsValue> {
+    if count == 0 || !spectra.len().is_multiple_of(count) || !(spectra.len() / count).is_multiple_of(2) {
+        return Err(FftError::InvalidLength {
+            got: spectra.len(),
+            expected: format!("{} equal interleaved spectra", count),
+        }
+        .into());
+    }
+    if weights.len() != count {
+        return Err(FftError::LengthMismatch {
+            expected: count,
+            got: weights.len(),
+        }
+        .into());
+    }
+
+    let length = spectra.len() / count;
+    let mut blended = vec![0.0f32; length];
+    for (spectrum, &weight) in spectra.chunks_exact(length).zip(weights.iter()) {
+        for (out, &value) in blended.iter_mut().zip(spectrum.iter()) {
+            *out += weight * value;
+        }
+    }
+
+    Ok(blended)
+}
+
+/// Compute the spectral entropy of a spectrum
+///
+/// Treats the normalized power `p_i = |c_i|² / Σ|c|²` as a probability
+/// distribution and returns `-Σ p_i log2(p_i)`, skipping zero-power bins. A
+/// single peak gives 0; power spread evenly over `n` bins gives `log2(n)`.
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients
+///
+/// # Returns
+/// * `f32` - Entropy in bits (0 for an all-zero spectrum)
+#[wasm_bindgen]
+pub fn spectral_entropy(coefficients_real: &[f32], coefficients_imag: &[f32]) -> Result<f32, JsValue> {
+    let total = calculate_energy(coefficients_real, coefficients_imag)?;
+    if total == 0.0 {
+        return Ok(0.0);
+    }
+
+    let entropy: f32 = coefficients_real
+        .iter()
+        .zip(coefficients_imag.iter())
+        .map(|(&re, &im)| (re * re + im * im) / total)
+        .filter(|&p| p > 0.0)
+        .map(|p| -p * p.log2())
         .sum();
 
-    Ok(energy)
-}
+    Ok(entropy)
+}
+
+/// Measure the sparsity of a spectrum
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients
+/// * `threshold` - Fraction of the peak magnitude below which a coefficient counts as negligible
+///
+/// # Returns
+/// * `f32` - Fraction of coefficients whose magnitude is below `threshold * peak`
+///   (1.0 for an all-zero spectrum)
+#[wasm_bindgen]
+pub fn spectrum_sparsity(coefficients_real: &[f32], coefficients_imag: &[f32], threshold: f32) -> Result<f32, JsValue> {
+    if coefficients_real.len() != coefficients_imag.len() {
+        return Err(FftError::LengthMismatch {
+            expected: coefficients_real.len(),
+            got: coefficients_imag.len(),
+        }
+        .into());
+    }
+    if coefficients_real.is_empty() {
+        return Err(FftError::EmptyInput.into());
+    }
+
+    let magnitudes: Vec<f32> = coefficients_real
+        .iter()
+        .zip(coefficients_imag.iter())
+        .map(|(&re, &im)| re.hypot(im))
+        .collect();
+
+    let peak = magnitudes.iter().fold(0.0f32, |m, &v| m.max(v));
+    if peak == 0.0 {
+        return Ok(1.0);
+    }
+
+    let cutoff = threshold * peak;
+    let negligible = magnitudes.iter().filter(|&&m| m < cutoff).count();
+    Ok(negligible as f32 / magnitudes.len() as f32)
+}
+
+/// Count the frequency modes that carry significant energy
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of FFT coefficients
+/// * `coefficients_imag` - Imaginary parts of FFT coefficients
+/// * `threshold` - Fraction of the peak power a mode must exceed to count
+///
+/// # Returns
+/// * `Float32Array` - `[mode_count, participation_ratio]` where the participation
+///   ratio is `(Σp)² / Σp²` over all per-coefficient powers `p`
+#[wasm_bindgen]
+pub fn effective_mode_count(
+    coefficients_real: &[f32],
+    coefficients_imag: &[f32],
+    threshold: f32,
+) -> Result<Vec<f32>, JsValue> {
+    if coefficients_real.len() != coefficients_imag.len() {
+        return Err(FftError::LengthMismatch {
+            expected: coefficients_real.len(),
+            got: coefficients_imag.len(),
+        }
+        .into());
+    }
+
+    let powers: Vec<f32> = coefficients_real
+        .iter()
+        .zip(coefficients_imag.iter())
+        .map(|(&re, &im)| re * re + im * im)
+        .collect();
+
+    let peak = powers.iter().fold(0.0f32, |m, &p| m.max(p));
+    if peak == 0.0 {
+        return Ok(vec![0.0, 0.0]);
+    }
+
+    let cutoff = threshold * peak;
+    let count = powers.iter().filter(|&&p| p > cutoff).count();
+
+    Ok(vec![count as f32, participation_ratio(&powers)])
+}
+
+/// Participation ratio `(Σp)² / Σp²` of per-coefficient powers (0 if all zero)
+fn participation_ratio(powers: &[f32]) -> f32 {
+    let sum: f64 = powers.iter().map(|&p| p as f64).sum();
+    let sum_sq: f64 = powers.iter().map(|&p| (p as f64) * (p as f64)).sum();
+    if sum_sq == 0.0 {
+        return 0.0;
+    }
+    (sum * sum / sum_sq) as f32
+}
+
+/// Compute the group delay (negative phase derivative) along one axis
+///
+/// For each line along `axis`, the phase difference between neighbouring bins is
+/// wrapped into (-π, π], which is the derivative of the unwrapped phase as long as
+/// the true phase step is smaller than π. Differences are taken forward in
+/// standard (non-shifted) FFT order and wrap from the last bin back to bin 0, so
+/// each spectrum line is treated as periodic. Bins with zero magnitude have no
+/// defined phase and report a delay of 0.
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of the spectrum (length size^3)
+/// * `coefficients_imag` - Imaginary parts of the spectrum (length size^3)
+/// * `size` - Size of each dimension
+/// * `axis` - Axis to differentiate along (0 = X, 1 = Y, 2 = Z)
+///
+/// # Returns
+/// * `Float32Array` - Group delay in voxels per coefficient (length size^3)
+#[wasm_bindgen]
+pub fn group_delay_axis(
+    coefficients_real: &[f32],
+    coefficients_imag: &[f32],
+    size: usize,
+    axis: usize,
+) -> Result<Vec<f32>, JsValue> {
+    let total_size = cube_volume(size)?;
+    validate_pair_length(coefficients_real, coefficients_imag, total_size)?;
+    validate_axis(axis)?;
+
+    let stride = match axis {
+        0 => 1,
+        1 => size,
+        _ => size * size,
+    };
+    let bin_spacing = 2.0 * std::f32::consts::PI / size as f32;
+    let phase = |i: usize| coefficients_imag[i].atan2(coefficients_real[i]);
+    let is_zero = |i: usize| coefficients_real[i] == 0.0 && coefficients_imag[i] == 0.0;
+
+    let mut delay = try_alloc(total_size, 0.0f32, size)?;
+    for (i, value) in delay.iter_mut().enumerate() {
+        let k = (i / stride) % size;
+        let next = if k + 1 == size { i + stride - size * stride } else { i + stride };
+        if is_zero(i) || is_zero(next) {
+            continue;
+        }
+
+        let mut step = phase(next) - phase(i);
+        if step > std::f32::consts::PI {
+            step -= 2.0 * std::f32::consts::PI;
+        } else if step <= -std::f32::consts::PI {
+            step += 2.0 * std::f32::consts::PI;
+        }
+        *value = -step / bin_spacing;
+    }
+
+    Ok(delay)
+}
+
+/// Estimate the aliasing risk of downsampling a spectrum to a smaller grid
+///
+/// A grid of `target_size` voxels per axis can only represent signed
+/// frequencies strictly below its Nyquist frequency, `|k| < target_size / 2`
+/// in the source's integer frequency indices (cycles per volume). Any
+/// coefficient at or beyond that on some axis, including the target Nyquist
+/// itself, would fold onto another frequency after downsampling.
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of the spectrum (length size^3)
+/// * `coefficients_imag` - Imaginary parts of the spectrum (length size^3)
+/// * `size` - Size of each dimension of the source
+/// * `target_size` - Size of each dimension after downsampling (1..=size)
+///
+/// # Returns
+/// * `f32` - Fraction of the total energy that would alias (0 for an all-zero spectrum)
+#[wasm_bindgen]
+pub fn aliasing_risk(
+    coefficients_real: &[f32],
+    coefficients_imag: &[f32],
+    size: usize,
+    target_size: usize,
+) -> Result<f32, JsValue> {
+    let total_size = cube_volume(size)?;
+    validate_pair_length(coefficients_real, coefficients_imag, total_size)?;
+    if target_size == 0 || target_size > size {
+        return Err(FftError::InvalidArgument {
+            name: "target size",
+            reason: format!("{}. Expected 1..={}", target_size, size),
+        }
+        .into());
+    }
+
+    let nyquist = target_size as f32 / 2.0;
+    let mut total = 0.0f64;
+    let mut aliased = 0.0f64;
+    for (i, (&re, &im)) in coefficients_real.iter().zip(coefficients_imag.iter()).enumerate() {
+        let power = (re * re + im * im) as f64;
+        total += power;
+        let beyond = [i % size, (i / size) % size, i / (size * size)]
+            .iter()
+            .any(|&k| signed_frequency(k, size).abs() >= nyquist);
+        if beyond {
+            aliased += power;
+        }
+    }
+
+    if total == 0.0 {
+        return Ok(0.0);
+    }
+    Ok((aliased / total) as f32)
+}
+
+/// Compute the spectral energy marginalized onto each axis
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of the spectrum (length size^3)
+/// * `coefficients_imag` - Imaginary parts of the spectrum (length size^3)
+/// * `size` - Size of each dimension
+///
+/// # Returns
+/// * `Float32Array` - Three concatenated arrays of length `size` for X, Y and Z.
+///   Entry `k` of an axis is the total energy `Σ|c|²` of all coefficients whose
+///   index along that axis is `k`, in standard (non-shifted) FFT order.
+#[wasm_bindgen]
+pub fn marginal_energy(coefficients_real: &[f32], coefficients_imag: &[f32], size: usize) -> Result<Vec<f32>, JsValue> {
+    let total_size = cube_volume(size)?;
+    validate_pair_length(coefficients_real, coefficients_imag, total_size)?;
+
+    let mut marginals = vec![0.0f32; 3 * size];
+    for (i, (&re, &im)) in coefficients_real.iter().zip(coefficients_imag.iter()).enumerate() {
+        let power = re * re + im * im;
+        marginals[i % size] += power;
+        marginals[size + (i / size) % size] += power;
+        marginals[2 * size + i / (size * size)] += power;
+    }
+
+    Ok(marginals)
+}
+
+/// Get the progressive transmission order of a size^3 spectrum
+///
+/// Coefficients are sorted by increasing squared radial frequency
+/// `kx² + ky² + kz²` (signed indices, standard FFT order); ties keep their
+/// original linear order, so the ordering is deterministic.
+///
+/// # Arguments
+/// * `size` - Size of each dimension
+///
+/// # Returns
+/// * `Uint32Array` - Entry `j` is the original flat index of the `j`-th coefficient
+///   in the reordered stream; errors if size^3 indices do not fit in `u32`
+#[wasm_bindgen]
+pub fn frequency_order(size: usize) -> Result<Vec<u32>, JsValue> {
+    Ok(frequency_order_indices(size)?)
+}
+
+/// Shared implementation of `frequency_order` and the reordering functions
+fn frequency_order_indices(size: usize) -> Result<Vec<u32>, FftError> {
+    let total_size = u32::try_from(cube_volume(size)?).map_err(|_| FftError::InvalidArgument {
+        name: "size",
+        reason: format!("{}^3 coefficients exceed the u32 index range", size),
+    })?;
+    let radius_sq = |i: usize| {
+        let k = |v: usize| {
+            let f = signed_frequency(v, size) as i64;
+            f * f
+        };
+        k(i % size) + k((i / size) % size) + k(i / (size * size))
+    };
+
+    let mut order: Vec<u32> = (0..total_size).collect();
+    order.sort_by_key(|&i| radius_sq(i as usize));
+    Ok(order)
+}
+
+/// Reorder an interleaved spectrum from lowest to highest radial frequency
+///
+/// The reordered stream lets a receiver render a progressively refined field as
+/// packets arrive. Coefficient `j` of the output is coefficient
+/// `frequency_order(size)[j]` of the input; `restore_frequency_order` inverts it.
+///
+/// # Arguments
+/// * `data` - Interleaved spectrum (length 2 * size^3)
+/// * `size` - Size of each dimension
+///
+/// # Returns
+/// * `Float32Array` - Reordered interleaved spectrum
+#[wasm_bindgen]
+pub fn reorder_by_frequency(data: &[f32], size: usize) -> Result<Vec<f32>, JsValue> {
+    validate_spectrum_length(data, size)?;
+
+    let reordered: Vec<f32> = frequency_order_indices(size)?
+        .iter()
+        .flat_map(|&i| [data[2 * i as usize], data[2 * i as usize + 1]])
+        .collect();
+
+    Ok(reordered)
+}
+
+/// Restore the standard layout of a spectrum reordered by `reorder_by_frequency`
+///
+/// A partially received stream can be zero-filled to full length before restoring,
+/// which leaves the missing high frequencies at zero.
+///
+/// # Arguments
+/// * `data` - Reordered interleaved spectrum (length 2 * size^3)
+/// * `size` - Size of each dimension
+///
+/// # Returns
+/// * `Float32Array` - Interleaved spectrum in standard FFT order
+#[wasm_bindgen]
+pub fn restore_frequency_order(data: &[f32], size: usize) -> Result<Vec<f32>, JsValue> {
+    validate_spectrum_length(data, size)?;
+
+    let mut restored = vec![0.0f32; data.len()];
+    for (j, &i) in frequency_order_indices(size)?.iter().enumerate() {
+        restored[2 * i as usize] = data[2 * j];
+        restored[2 * i as usize + 1] = data[2 * j + 1];
+    }
+
+    Ok(restored)
+}
+
+/// Validate that interleaved data holds a full size^3 spectrum
+fn validate_spectrum_length(data: &[f32], size: usize) -> Result<(), FftError> {
+    let expected = cube_volume(size)?
+        .checked_mul(2)
+        .ok_or_else(|| volume_overflow(size))?;
+    if data.len() != expected {
+        return Err(FftError::LengthMismatch {
+            expected,
+            got: data.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Compute a radially reduced bispectrum for nonlinear phase-coupling analysis
+///
+/// The full 3D bispectrum `B(k1,k2) = X(k1)X(k2)conj(X(k1+k2))` has size^6 entries,
+/// so wavevectors are grouped into radial shells: shell `r` holds every `k` whose
+/// length `sqrt(kx² + ky² + kz²)` (signed indices, cycles per volume) rounds to
+/// `r`, for `r` in `0..=size/2`; corner frequencies beyond `size/2` are dropped.
+/// For each shell triplet `(r1, r2, r3)` the triple products of all `k1` in
+/// `r1` and `k2` in `r2` whose sum `k3 = k1 + k2` (modulo `size`) lies in `r3`
+/// are summed, then divided by the number of such triangles.
+///
+/// The sums are evaluated without visiting every pair: with `F_r` the inverse
+/// transform of the spectrum restricted to shell `r`, the sum over triangles
+/// equals `Σx F_r1(x) F_r2(x) conj(F_r3(x)) / size^3`, and the triangle count is
+/// the same expression over shell indicator fields. This costs `2 * (size/2 + 1)`
+/// inverse transforms and memory for as many full complex volumes.
+///
+/// # Arguments
+/// * `coefficients_real` - Real parts of the spectrum (length size^3)
+/// * `coefficients_imag` - Imaginary parts of the spectrum (length size^3)
+/// * `size` - Size of each dimension
+///
+/// # Returns
+/// * `Float32Array` - Mean bispectrum magnitudes of length `(size/2 + 1)^3`,
+///   indexed `[r1][r2][r3]`; triplets with no closed triangle are 0
+#[wasm_bindgen]
+pub fn bispectrum_1d(
+    coefficients_real: &[f32],
+    coefficients_imag: &[f32],
+    size: usize,
+) -> Result<Vec<f32>, JsValue> {
+    Ok(shell_bispectrum(coefficients_real, coefficients_imag, size)?)
+}
+
+/// Shared implementation of `bispectrum_1d`
+fn shell_bispectrum(coefficients_real: &[f32], coefficients_imag: &[f32], size: usize) -> Result<Vec<f32>, FftError> {
+    validate_size(size)?;
+    let total_size = size * size * size;
+    validate_pair_length(coefficients_real, coefficients_imag, total_size)?;
+
+    let bins = size / 2 + 1;
+    let shell = |i: usize| {
+        let fx = signed_frequency(i % size, size);
+        let fy = signed_frequency((i / size) % size, size);
+        let fz = signed_frequency(i / (size * size), size);
+        let r = (fx * fx + fy * fy + fz * fz).sqrt().round() as usize;
+        (r < bins).then_some(r)
+    };
+
+    // Volume `r` holds the spectrum restricted to shell `r`, volume `bins + r`
+    // that shell's indicator; all are inverse transformed in one batch
+    let zero = Complex::new(0.0f32, 0.0f32);
+    let mut shells = try_alloc(2 * bins * total_size, zero, size)?;
+    for i in 0..total_size {
+        if let Some(r) = shell(i) {
+            shells[r * total_size + i] = Complex::new(coefficients_real[i], coefficients_imag[i]);
+            shells[(bins + r) * total_size + i] = Complex::new(1.0, 0.0);
+        }
+    }
+    let (_, fft_inverse) = FFTPlanCache::new().get_plans(size)?;
+    transform_axes(&mut shells, [size; 3], [&fft_inverse; 3], |_, _| {}, &mut |_| {})?;
+    let volumes: Vec<&[Complex<f32>]> = shells.chunks_exact(total_size).collect();
+    let (fields, indicators) = volumes.split_at(bins);
+
+    let mut output = try_alloc(bins * bins * bins, 0.0f32, size)?;
+    for r1 in 0..bins {
+        for r2 in r1..bins {
+            // Shell rounding keeps |k3| within one bin of the triangle inequality
+            let r3_min = (r2 - r1).saturating_sub(1);
+            let r3_max = (r1 + r2 + 1).min(bins - 1);
+            for r3 in r3_min..=r3_max {
+                let count: f64 = (0..total_size)
+                    .map(|x| {
+                        let [a, b, c] = [r1, r2, r3].map(|r| indicators[r][x]);
+                        (a * b * c.conj()).re as f64
+                    })
+                    .sum();
+                // Counts are integers up to rounding error in the transforms
+                let count = (count / total_size as f64).round();
+                if count < 1.0 {
+                    continue;
+                }
+
+                let mut sum = Complex::new(0.0f64, 0.0f64);
+                let [a, b, c] = [r1, r2, r3].map(|r| fields[r]);
+                for x in 0..total_size {
+                    let product = a[x] * b[x] * c[x].conj();
+                    sum += Complex::new(product.re as f64, product.im as f64);
+                }
+                let magnitude = (sum.norm() / total_size as f64 / count) as f32;
+                output[(r1 * bins + r2) * bins + r3] = magnitude;
+                output[(r2 * bins + r1) * bins + r3] = magnitude;
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Describe the cost of a transform size without creating it
+///
+/// # Arguments
+/// * `size` - Size of each dimension
+///
+/// # Returns
+/// * `Float64Array` - `[total_elements, output_bytes, estimated_plan_bytes]`, where
+///   `output_bytes` is the size of one interleaved f32 spectrum; errors if the
+///   size is not supported
+#[wasm_bindgen]
+pub fn size_info(size: usize) -> Result<Vec<f64>, JsValue> {
+    validate_size(size)?;
+
+    let total_elements = size * size * size;
+    let output_bytes = 2 * total_elements * std::mem::size_of::<f32>();
+    Ok(vec![
+        total_elements as f64,
+        output_bytes as f64,
+        estimated_plan_bytes(size) as f64,
+    ])
+}
+
+/// List the cube sizes accepted by `FFT3D::new`
+///
+/// Generated from the same bounds the constructor validates against, so a UI
+/// can build its size choices without hard-coding them.
+///
+/// # Returns
+/// * `Uint32Array` - Supported sizes in increasing order
+#[wasm_bindgen]
+pub fn supported_sizes() -> Vec<u32> {
+    supported_size_iter().map(|size| size as u32).collect()
+}
+
+/// Find the smallest supported cube size that can hold `n` values
+///
+/// # Arguments
+/// * `n` - Number of values
+///
+/// # Returns
+/// * `Option<usize>` - Smallest supported size with `size^3 >= n`, or `None` if `n`
+///   exceeds the largest supported cube
+#[wasm_bindgen]
+pub fn best_fit_size(n: usize) -> Option<usize> {
+    supported_size_iter().find(|&size| size * size * size >= n)
+}
+
+/// Forward-transform real data of arbitrary length
+///
+/// Picks the size with `best_fit_size`, appends zeros after the data in linear
+/// (x fastest) order up to `size^3` values, and performs the forward transform.
+/// The data therefore fills the leading rows and slices of the cube; it is not
+/// reshaped or centered.
+///
+/// # Arguments
+/// * `data` - Real values (at most the largest supported cube)
+///
+/// # Returns
+/// * `Float32Array` - Interleaved real/imag output (length = 2 * size^3, from which
+///   the chosen size can be recovered)
+#[wasm_bindgen]
+pub fn forward_auto(data: &[f32]) -> Result<Vec<f32>, JsValue> {
+    if data.is_empty() {
+        return Err(FftError::EmptyInput.into());
+    }
+    let size = best_fit_size(data.len()).ok_or_else(|| {
+        JsValue::from(FftError::InvalidLength {
+            got: data.len(),
+            expected: format!("at most {} values", MAX_SIZE * MAX_SIZE * MAX_SIZE),
+        })
+    })?;
+
+    let mut fft = FFT3D::new(size)?;
+    let mut real = data.to_vec();
+    real.resize(fft.total_size(), 0.0);
+    let imag = vec![0.0f32; fft.total_size()];
+    fft.forward(&real, &imag)
+}
+
+/// Measure the average duration of a forward+inverse round trip
+///
+/// Creates a transformer, fills a buffer with `fill_random` data and runs one
+/// untimed warm-up round trip so plan creation and setup allocations are not
+/// counted. The timed loop transforms the same buffer in place, using
+/// `performance.now()` when available.
+///
+/// # Arguments
+/// * `size` - Cube size to benchmark
+/// * `iterations` - Number of timed round trips (must be at least 1)
+///
+/// # Returns
+/// * `f64` - Average milliseconds per round trip
+#[wasm_bindgen]
+pub fn benchmark(size: usize, iterations: usize) -> Result<f64, JsValue> {
+    validate_size(size)?;
+    if iterations == 0 {
+        return Err(FftError::InvalidArgument {
+            name: "iteration count",
+            reason: "must be at least 1".to_string(),
+        }
+        .into());
+    }
+
+    let mut fft = FFT3D::new(size)?;
+    let mut real = try_alloc(fft.total_size, 0.0f32, size)?;
+    let mut imag = try_alloc(fft.total_size, 0.0f32, size)?;
+    fill_random(&mut real, 1);
+    fill_random(&mut imag, 2);
+    let mut buffer = fft.complex_buffer(&real, &imag)?;
+
+    let round_trip = |fft: &mut FFT3D, buffer: &mut [Complex<f32>]| -> Result<(), JsValue> {
+        fft.fft_3d(buffer, true)?;
+        fft.fft_3d(buffer, false)?;
+        fft.normalize_inverse(buffer);
+        Ok(())
+    };
+    round_trip(&mut fft, &mut buffer)?;
+
+    let start_ms = now_ms();
+    for _ in 0..iterations {
+        round_trip(&mut fft, &mut buffer)?;
+    }
+    Ok((now_ms() - start_ms) / iterations as f64)
+}
+
+/// Fill a buffer with deterministic pseudo-random values
+/// Uses a seeded xorshift64* generator so benchmarks and tests are reproducible
+///
+/// # Arguments
+/// * `buf` - Buffer to fill with values in [-1, 1)
+/// * `seed` - Generator seed (any value, including 0)
+#[wasm_bindgen]
+pub fn fill_random(buf: &mut [f32], seed: u64) {
+    // SplitMix64 scramble so that small or zero seeds still give a good state
+    let mut state = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    state ^= state >> 31;
+    if state == 0 {
+        state = 0x9E37_79B9_7F4A_7C15;
+    }
+
+    for value in buf.iter_mut() {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        let bits = state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+
+        // Top 24 bits map exactly onto the f32 mantissa
+        *value = (bits >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0;
+    }
+}
+
+/// URL-safe base64 alphabet (RFC 4648 section 5)
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode an interleaved spectrum as a URL-safe base64 string
+///
+/// Each f32 is serialized as its 4 little-endian bytes and the byte stream is
+/// encoded with the URL-safe alphabet (`-` and `_`) without padding, so the
+/// result can be placed directly in a URL.
+///
+/// # Arguments
+/// * `data` - Interleaved real/imag spectrum
+///
+/// # Returns
+/// * `String` - Base64 encoding of the spectrum
+#[wasm_bindgen]
+pub fn spectrum_to_base64(data: &[f32]) -> String {
+    let bytes: Vec<u8> = data.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for group in bytes.chunks(3) {
+        let b = [group[0], *group.get(1).unwrap_or(&0), *group.get(2).unwrap_or(&0)];
+        let bits = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..=group.len() {
+            encoded.push(BASE64_ALPHABET[(bits >> (18 - 6 * i) & 0x3F) as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Decode a spectrum produced by `spectrum_to_base64`
+///
+/// Accepts the URL-safe alphabet with or without trailing `=` padding. The
+/// decoded data must be an interleaved spectrum of length `2 * size^3` for a
+/// supported size.
+///
+/// # Arguments
+/// * `encoded` - Base64 string
+///
+/// # Returns
+/// * `Float32Array` - Interleaved real/imag spectrum
+#[wasm_bindgen]
+pub fn spectrum_from_base64(encoded: &str) -> Result<Vec<f32>, JsValue> {
+    let symbols = encoded.trim_end_matches('=').as_bytes();
+    if symbols.len() % 4 == 1 {
+        return Err(FftError::InvalidLength {
+            got: symbols.len(),
+            expected: "a base64 symbol count that is not 1 mod 4".to_string(),
+        }
+        .into());
+    }
+
+    let mut bytes = Vec::with_capacity(symbols.len() * 3 / 4);
+    for group in symbols.chunks(4) {
+        let mut bits = 0u32;
+        for (i, &symbol) in group.iter().enumerate() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|&a| a == symbol)
+                .ok_or_else(|| FftError::InvalidArgument {
+                    name: "base64 character",
+                    reason: (symbol as char).to_string(),
+                })?;
+            bits |= (value as u32) << (18 - 6 * i);
+        }
+        bytes.extend_from_slice(&bits.to_be_bytes()[1..group.len()]);
+    }
+
+    if bytes.len() % 4 != 0 {
+        return Err(FftError::InvalidLength {
+            got: bytes.len(),
+            expected: "a whole number of 4-byte f32 values".to_string(),
+        }
+        .into());
+    }
+    let values: Vec<f32> = bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+
+    if !supported_size_iter().any(|n| 2 * n * n * n == values.len()) {
+        return Err(FftError::InvalidLength {
+            got: values.len(),
+            expected: format!("2 * size^3 for a power-of-two size from {} to {}", MIN_SIZE, MAX_SIZE),
+        }
+        .into());
+    }
+
+    Ok(values)
+}
+
+/// Compute a 64-bit FNV-1a hash of a spectrum for use as a cache key
+///
+/// The hash runs over the little-endian bytes of each value's bit pattern, so it
+/// is identical on every platform and distinguishes values that compare equal
+/// as floats (e.g. `0.0` and `-0.0`). Not suitable for cryptographic use.
+///
+/// # Arguments
+/// * `data` - Interleaved real/imag spectrum
+///
+/// # Returns
+/// * `String` - 16-digit lowercase hex hash
+#[wasm_bindgen]
+pub fn spectrum_hash(data: &[f32]) -> String {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = data
+        .iter()
+        .flat_map(|v| v.to_bits().to_le_bytes())
+        .fold(FNV_OFFSET, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME));
+
+    format!("{:016x}", hash)
+}
+
+/// Check whether the module was compiled with WebAssembly SIMD (`simd128`)
+///
+/// SIMD only speeds up the transforms when the module is built with
+/// `-C target-feature=+simd128` and the browser supports WASM SIMD. A SIMD build
+/// fails to instantiate on a runtime without support, so use
+/// `simd_runtime_supported` to tell whether a SIMD build would load.
+#[wasm_bindgen]
+pub fn simd_enabled() -> bool {
+    cfg!(target_feature = "simd128")
+}
+
+/// Check whether the current WebAssembly runtime supports SIMD
+///
+/// Validates a minimal module using a `v128` instruction. Always false outside
+/// a WebAssembly host.
+#[wasm_bindgen]
+pub fn simd_runtime_supported() -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        // (module (func (result v128) i32.const 0 i8x16.splat i8x16.popcnt))
+        const SIMD_PROBE: [u8; 31] = [
+            0, 97, 115, 109, 1, 0, 0, 0, 1, 5, 1, 96, 0, 1, 123, 3, 2, 1, 0, 10, 10, 1, 8, 0, 65, 0,
+            253, 15, 253, 98, 11,
+        ];
+        let bytes = js_sys::Uint8Array::from(&SIMD_PROBE[..]);
+        js_sys::WebAssembly::validate(&bytes).unwrap_or(false)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        false
+    }
+}
+
+/// Get version information
+#[wasm_bindgen]
+pub fn get_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// System allocator that counts allocations made on the current thread
+    ///
+    /// Per-thread counts keep the parallel test runner from disturbing
+    /// allocation assertions.
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    /// Number of allocations `f` makes on the current thread
+    fn count_allocations(f: impl FnOnce()) -> usize {
+        let before = ALLOCATIONS.with(Cell::get);
+        f();
+        ALLOCATIONS.with(Cell::get) - before
+    }
+
+    /// Forward transform of seeded random data, used as a drift-detection snapshot
+    fn golden_forward(size: usize, seed: u64) -> Vec<f32> {
+        let total = size * size * size;
+        let mut real = vec![0.0f32; total];
+        let mut imag = vec![0.0f32; total];
+        fill_random(&mut real, seed);
+        fill_random(&mut imag, seed.wrapping_add(1));
+
+        let mut fft = FFT3D::new(size).unwrap();
+        fft.forward(&real, &imag).unwrap()
+    }
+
+    #[test]
+    fn test_fft_creation() {
+        let fft = FFT3D::new(8);
+        assert!(fft.is_ok());
+
+        let fft = fft.unwrap();
+        assert_eq!(fft.size(), 8);
+        assert_eq!(fft.total_size(), 512);
+    }
+
+    #[test]
+    fn test_normalization_factor() {
+        let fft = FFT3D::new(16).unwrap();
+        assert_eq!(fft.normalization_factor(), 1.0 / 4096.0);
+    }
+
+    #[test]
+    fn test_orthonormal_per_axis_roundtrip() {
+        let mut fft = FFT3D::new(16).unwrap();
+        fft.set_norm(NormMode::OrthonormalPerAxis);
+        assert_eq!(fft.norm_mode(), NormMode::OrthonormalPerAxis);
+        assert_eq!(fft.normalization_factor(), 1.0);
+
+        let mut real = vec![0.0f32; 4096];
+        let mut imag = vec![0.0f32; 4096];
+        fill_random(&mut real, 21);
+        fill_random(&mut imag, 22);
+
+        // DC is the sum scaled by 1 / sqrt(N)
+        let spectrum = fft.forward(&real, &imag).unwrap();
+        let sum: f32 = real.iter().sum();
+        assert!((spectrum[0] - sum / 64.0).abs() < 1e-4);
+
+        // Parseval holds without extra factors
+        let spatial: f32 = real.iter().chain(imag.iter()).map(|v| v * v).sum();
+        let spectral: f32 = spectrum.iter().map(|v| v * v).sum();
+        assert!((spatial - spectral).abs() < 1e-3 * spatial);
+
+        let fwd_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let fwd_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        let inverse = fft.inverse(&fwd_real, &fwd_imag).unwrap();
+        for (i, (&re, &im)) in real.iter().zip(imag.iter()).enumerate() {
+            assert!((inverse[2 * i] - re).abs() < 1e-5);
+            assert!((inverse[2 * i + 1] - im).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_norm_modes_roundtrip() {
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 111);
+        fill_random(&mut imag, 112);
+        let sum: f32 = real.iter().sum();
+
+        let mut reference = FFT3D::new(8).unwrap();
+        reference.set_norm(NormMode::OrthonormalPerAxis);
+        let per_axis = reference.forward(&real, &imag).unwrap();
+
+        for (mode, dc) in [
+            (NormMode::Backward, sum),
+            (NormMode::Forward, sum / 512.0),
+            (NormMode::Ortho, sum / 512f32.sqrt()),
+        ] {
+            let mut fft = FFT3D::new(8).unwrap();
+            fft.set_norm(mode);
+            let spectrum = fft.forward(&real, &imag).unwrap();
+            assert!((spectrum[0] - dc).abs() < 1e-4, "DC mismatch for {:?}", mode);
+            if mode == NormMode::Ortho {
+                for (a, b) in spectrum.iter().zip(per_axis.iter()) {
+                    assert!((a - b).abs() < 1e-5);
+                }
+            }
+
+            let fwd_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+            let fwd_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+            let inverse = fft.inverse(&fwd_real, &fwd_imag).unwrap();
+            for (i, &re) in real.iter().enumerate() {
+                assert!((inverse[2 * i] - re).abs() < 1e-5, "Roundtrip mismatch for {:?}", mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_invalid_size() {
+        // FFT3D::new and new_dims reject exactly the sizes validate_size rejects
+        for size in [0, 1, 5, 48, 512] {
+            let error = validate_size(size).unwrap_err();
+            assert_eq!(error, FftError::UnsupportedSize { size });
+            assert_eq!(error.code(), "UNSUPPORTED_SIZE");
+        }
+        for size in supported_size_iter() {
+            assert_eq!(validate_size(size), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_forward_inverse_roundtrip() {
+        for n in [8, 64] {
+            let mut fft = FFT3D::new(n).unwrap();
+            let size = n * n * n;
+            assert_eq!(fft.total_size(), size);
+
+            // Create test data (real impulse at origin)
+            let mut input_real = vec![0.0f32; size];
+            let input_imag = vec![0.0f32; size];
+            input_real[0] = 1.0;
+
+            // Forward transform
+            let forward = fft.forward(&input_real, &input_imag).unwrap();
+
+            // Extract real and imaginary parts
+            let fwd_real: Vec<f32> = forward.iter().step_by(2).copied().collect();
+            let fwd_imag: Vec<f32> = forward.iter().skip(1).step_by(2).copied().collect();
+
+            // Inverse transform
+            let inverse = fft.inverse(&fwd_real, &fwd_imag).unwrap();
+
+            // Extract result
+            let inv_real: Vec<f32> = inverse.iter().step_by(2).copied().collect();
+
+            // Check roundtrip (should recover original impulse)
+            assert!((inv_real[0] - 1.0).abs() < 1e-5, "Expected ~1.0, got {}", inv_real[0]);
+
+            // Other values should be near zero
+            for (i, &v) in inv_real.iter().enumerate().skip(1) {
+                assert!(v.abs() < 1e-5, "Expected ~0.0 at {}, got {}", i, v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft3d64_forward_interleaved() {
+        let n = 8;
+        let fft = FFT3D64::new(n).unwrap();
+        let real: Vec<f64> = (0..512).map(|i| ((i * 37 % 101) as f64 - 50.0) / 7.0).collect();
+        let imag: Vec<f64> = (0..512).map(|i| ((i * 13 % 29) as f64).sin()).collect();
+        let interleaved: Vec<f64> = real.iter().zip(imag.iter()).flat_map(|(&re, &im)| [re, im]).collect();
+
+        let spectrum = fft.forward_f64_interleaved(&interleaved).unwrap();
+        assert_eq!(spectrum, fft.forward(&real, &imag).unwrap());
+    }
+
+    #[test]
+    fn test_fft3d64_roundtrip_precision() {
+        let n = 16;
+        let fft = FFT3D64::new(n).unwrap();
+        let size = n * n * n;
+
+        let input_real: Vec<f64> = (0..size).map(|i| ((i * 37 % 101) as f64 - 50.0) / 7.0).collect();
+        let input_imag: Vec<f64> = (0..size).map(|i| ((i * 13 % 29) as f64).sin()).collect();
+
+        let mut real = input_real.clone();
+        let mut imag = input_imag.clone();
+        for _ in 0..10 {
+            let spectrum = fft.forward(&real, &imag).unwrap();
+            let re: Vec<f64> = spectrum.iter().step_by(2).copied().collect();
+            let im: Vec<f64> = spectrum.iter().skip(1).step_by(2).copied().collect();
+            let output = fft.inverse(&re, &im).unwrap();
+            real = output.iter().step_by(2).copied().collect();
+            imag = output.iter().skip(1).step_by(2).copied().collect();
+        }
+
+        for i in 0..size {
+            assert!((real[i] - input_real[i]).abs() < 1e-12, "real drift at {}: {}", i, real[i] - input_real[i]);
+            assert!((imag[i] - input_imag[i]).abs() < 1e-12, "imag drift at {}: {}", i, imag[i] - input_imag[i]);
+        }
+
+        // Same single cycle through the f32 transformer is far less accurate
+        let mut fft32 = FFT3D::new(n).unwrap();
+        let real32: Vec<f32> = input_real.iter().map(|&v| v as f32).collect();
+        let imag32: Vec<f32> = input_imag.iter().map(|&v| v as f32).collect();
+        let spectrum = fft32.forward(&real32, &imag32).unwrap();
+        let re: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let im: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        let output = fft32.inverse(&re, &im).unwrap();
+        let worst32 = output
+            .iter()
+            .step_by(2)
+            .zip(input_real.iter())
+            .map(|(&v, &x)| (v as f64 - x).abs())
+            .fold(0.0, f64::max);
+        assert!(worst32 > 1e-9);
+    }
+
+    #[test]
+    fn test_apply_window() {
+        let n = 8;
+        let fft = FFT3D::new(n).unwrap();
+        let size = n * n * n;
+        let input_real: Vec<f32> = (0..size).map(|i| (i % 7) as f32 + 1.0).collect();
+        let input_imag: Vec<f32> = (0..size).map(|i| (i % 5) as f32 - 2.0).collect();
+
+        let mut real = input_real.clone();
+        let mut imag = input_imag.clone();
+        fft.apply_window(&mut real, &mut imag, Window::Rectangular).unwrap();
+        assert_eq!(real, input_real);
+        assert_eq!(imag, input_imag);
+
+        fft.apply_window(&mut real, &mut imag, Window::Hann).unwrap();
+        for (x, y, z) in [(0, 0, 0), (n - 1, 0, 0), (0, n - 1, n - 1), (n - 1, n - 1, n - 1)] {
+            let i = (z * n + y) * n + x;
+            assert_eq!(real[i], 0.0);
+            assert_eq!(imag[i], 0.0);
+        }
+        // Interior samples are attenuated but not zeroed
+        let center = (4 * n + 4) * n + 4;
+        assert!(real[center] != 0.0 && real[center].abs() < input_real[center].abs());
+
+        // Coefficients are computed once per window and axis length
+        assert_eq!(fft.window_cache.borrow().len(), 2);
+        fft.apply_window(&mut real, &mut imag, Window::Hann).unwrap();
+        assert_eq!(fft.window_cache.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_apply_window_axes() {
+        let n = 8;
+        let fft = FFT3D::new(n).unwrap();
+        let mut data = vec![0.0f32; 2 * n * n * n];
+        fill_random(&mut data, 81);
+        let real: Vec<f32> = data.iter().step_by(2).copied().collect();
+        let imag: Vec<f32> = data.iter().skip(1).step_by(2).copied().collect();
+
+        // All three axes match apply_window
+        let (mut win_real, mut win_imag) = (real.clone(), imag.clone());
+        fft.apply_window(&mut win_real, &mut win_imag, Window::Hann).unwrap();
+        let mut all = data.clone();
+        fft.apply_window_axes(&mut all, Window::Hann, &[0, 1, 2, 2]).unwrap();
+        let interleaved: Vec<f32> = win_real.iter().zip(win_imag.iter()).flat_map(|(&re, &im)| [re, im]).collect();
+        assert_eq!(all, interleaved);
+
+        // Windowing X and Y only leaves the Z profile of a line untouched
+        let mut xy = data.clone();
+        fft.apply_window_axes(&mut xy, Window::Hann, &[0, 1]).unwrap();
+        let w = Window::Hann.coefficients(n);
+        for z in 0..n {
+            let i = (z * n + 3) * n + 5;
+            assert!((xy[2 * i] - data[2 * i] * w[5] * w[3]).abs() < 1e-6);
+        }
+        // The Z = 0 face is not zeroed, unlike with a full Hann window
+        assert!(xy[2 * ((3 * n) + 5)] != 0.0);
+
+        let mut unchanged = data.clone();
+        fft.apply_window_axes(&mut unchanged, Window::Blackman, &[]).unwrap();
+        assert_eq!(unchanged, data);
+    }
+
+    #[test]
+    fn test_gaussian_blur() {
+        let n = 16;
+        let mut fft = FFT3D::new(n).unwrap();
+        let size = n * n * n;
+        let input_real: Vec<f32> = (0..size).map(|i| ((i * 31 % 17) as f32 - 8.0) / 4.0).collect();
+        let input_imag = vec![0.0f32; size];
+
+        let unchanged = fft.gaussian_blur(&input_real, &input_imag, 0.0).unwrap();
+        let unchanged_real: Vec<f32> = unchanged.iter().step_by(2).copied().collect();
+        assert_eq!(unchanged_real, input_real);
+
+        let blurred = fft.gaussian_blur(&input_real, &input_imag, 2.0).unwrap();
+        let blurred_real: Vec<f32> = blurred.iter().step_by(2).copied().collect();
+        let blurred_imag: Vec<f32> = blurred.iter().skip(1).step_by(2).copied().collect();
+
+        let energy = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>();
+        assert!(energy(&blurred_real) < 0.5 * energy(&input_real));
+        assert!(blurred_imag.iter().all(|v| v.abs() < 1e-4));
+
+        // The DC component (the mean) is preserved
+        let mean = |v: &[f32]| v.iter().sum::<f32>() / v.len() as f32;
+        assert!((mean(&blurred_real) - mean(&input_real)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_resample_roundtrip() {
+        use std::f32::consts::PI;
+
+        // Low-frequency sinusoid sampled on a grid of side n
+        let field = |n: usize| -> Vec<f32> {
+            (0..n * n * n)
+                .map(|i| {
+                    let (x, y, z) = ((i % n) as f32, ((i / n) % n) as f32, (i / (n * n)) as f32);
+                    let t = n as f32;
+                    1.0 + (2.0 * PI * x / t).cos() + 0.5 * (2.0 * PI * 2.0 * y / t).sin() * (2.0 * PI * z / t).cos()
+                })
+                .collect()
+        };
+
+        let mut fft = FFT3D::new(8).unwrap();
+        let coarse = field(8);
+        let imag = vec![0.0f32; 512];
+
+        let up = fft.resample(&coarse, &imag, 16).unwrap();
+        assert_eq!(up.len(), 2 * 16 * 16 * 16);
+        let up_real: Vec<f32> = up.iter().step_by(2).copied().collect();
+        for (i, (&got, &want)) in up_real.iter().zip(field(16).iter()).enumerate() {
+            assert!((got - want).abs() < 1e-4, "upsample mismatch at {}: {} vs {}", i, got, want);
+        }
+        assert!(up.iter().skip(1).step_by(2).all(|v| v.abs() < 1e-4));
+
+        let mut fine = FFT3D::new(16).unwrap();
+        let down = fine.resample(&up_real, &vec![0.0f32; 4096], 8).unwrap();
+        for (i, (&got, &want)) in down.iter().step_by(2).zip(coarse.iter()).enumerate() {
+            assert!((got - want).abs() < 1e-4, "downsample mismatch at {}: {} vs {}", i, got, want);
+        }
+
+        // Amplitudes do not depend on the normalization convention
+        fft.set_norm(NormMode::Ortho);
+        let up_ortho = fft.resample(&coarse, &imag, 16).unwrap();
+        for (a, b) in up_ortho.iter().zip(up.iter()) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_threshold_coefficients() {
+        let fft = FFT3D::new(4).unwrap();
+        let input_real: Vec<f32> = (0..64).map(|i| i as f32 - 20.0).collect();
+        let input_imag = vec![0.5f32; 64];
+
+        let mut real = input_real.clone();
+        let mut imag = input_imag.clone();
+        assert_eq!(fft.threshold_coefficients(&mut real, &mut imag, 1.0).unwrap(), 64);
+        assert_eq!(real, input_real);
+
+        // ceil(0.3 * 64) = 20 largest magnitudes are the top of the ramp, 43 down to 24
+        assert_eq!(fft.threshold_coefficients(&mut real, &mut imag, 0.3).unwrap(), 20);
+        let kept: Vec<usize> = (0..64).filter(|&i| real[i] != 0.0).collect();
+        assert_eq!(kept.len(), 20);
+        assert!(kept.iter().all(|&i| (input_real[i]).abs() >= 24.0));
+        assert!((0..64).filter(|i| !kept.contains(i)).all(|i| imag[i] == 0.0));
+
+        assert_eq!(fft.threshold_coefficients(&mut real, &mut imag, 0.0).unwrap(), 0);
+        assert!(real.iter().chain(imag.iter()).all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_memory_estimates() {
+        let mut small = FFT3D::new(8).unwrap();
+        let mut large = FFT3D::new(32).unwrap();
+        assert_eq!(small.plan_cache.borrow().estimated_bytes(), 0);
+
+        let forward = |fft: &mut FFT3D, n: usize| {
+            fft.forward(&vec![1.0; n * n * n], &vec![0.0; n * n * n]).unwrap();
+        };
+        forward(&mut small, 8);
+        forward(&mut large, 32);
+
+        let (cache_small, cache_large) = (
+            small.plan_cache.borrow().estimated_bytes(),
+            large.plan_cache.borrow().estimated_bytes(),
+        );
+        assert!(cache_small > 0 && cache_large > cache_small);
+
+        // Dominated by the size^3 transform buffers, so 32^3 costs ~64x as much
+        let ratio = large.memory_footprint() as f64 / small.memory_footprint() as f64;
+        assert!(ratio > 32.0 && ratio < 80.0, "ratio {}", ratio);
+        assert!(small.memory_footprint() >= 2 * 512 * 8 + cache_small);
+    }
+
+    #[test]
+    fn test_verify_parseval() {
+        let n = 16;
+        let mut fft = FFT3D::new(n).unwrap();
+        let size = n * n * n;
+        let real: Vec<f32> = (0..size).map(|i| ((i * 7919 % 257) as f32 - 128.0) / 64.0).collect();
+        let imag: Vec<f32> = (0..size).map(|i| ((i * 31 % 13) as f32 - 6.0) / 8.0).collect();
+
+        for mode in [NormMode::Backward, NormMode::Forward, NormMode::Ortho, NormMode::OrthonormalPerAxis] {
+            fft.set_norm(mode);
+            fft.check_parseval(&real, &imag, 1e-4).unwrap();
+        }
+        assert_eq!(fft.verify_parseval(&vec![0.0; size], &vec![0.0; size]).unwrap(), 0.0);
+        assert_eq!(fft.check_parseval(&real, &imag, 0.0).unwrap_err().code(), "INVALID_INPUT");
+        assert_eq!(
+            fft.check_parseval(&real, &imag[1..], 1e-4),
+            Err(FftError::LengthMismatch { expected: size, got: size - 1 })
+        );
+    }
+
+    #[test]
+    fn test_dominant_frequency() {
+        use std::f32::consts::PI;
+
+        let n = 16;
+        let mut fft = FFT3D::new(n).unwrap();
+        let size = n * n * n;
+        let (kx, ky, kz) = (3.0, 14.0, 5.0);
+        let real: Vec<f32> = (0..size)
+            .map(|i| {
+                let (x, y, z) = ((i % n) as f32, ((i / n) % n) as f32, (i / (n * n)) as f32);
+                2.0 + (2.0 * PI * (kx * x + ky * y + kz * z) / n as f32).cos()
+            })
+            .collect();
+        let imag = vec![0.0f32; size];
+
+        let spectrum = fft.forward(&real, &imag).unwrap();
+        let re: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let im: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+
+        // The DC term (2 * size) is larger but skipped; the cosine splits into
+        // two bins of size/2 at +k and -k, and +k = (3, -2, 5) has the lower index
+        let dominant = fft.dominant_frequency(&re, &im).unwrap();
+        assert_eq!(&dominant[..3], &[3.0, -2.0, 5.0]);
+        assert!((dominant[3] - size as f32 / 2.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_progress_reporting() {
+        let n = 8;
+        let mut fft = FFT3D::new(n).unwrap();
+        let real: Vec<f32> = (0..512).map(|i| (i % 9) as f32).collect();
+        let imag = vec![0.0f32; 512];
+
+        // A null callback behaves like the plain transforms
+        let spectrum = fft.forward_with_progress(&real, &imag, None).unwrap();
+        assert_eq!(spectrum, fft.forward(&real, &imag).unwrap());
+        let re: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let im: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        assert_eq!(fft.inverse_with_progress(&re, &im, None).unwrap(), fft.inverse(&re, &im).unwrap());
+
+        // One report per axis pass for a single volume
+        let mut reports = Vec::new();
+        let mut buffer = fft.complex_buffer(&real, &imag).unwrap();
+        fft.fft_3d_with_progress(&mut buffer, true, Some(&mut |f| reports.push(f))).unwrap();
+        assert_eq!(reports, vec![1.0 / 3.0, 2.0 / 3.0, 1.0]);
+
+        // One Z report per sub-volume in batch mode
+        reports.clear();
+        let mut batch = [buffer.clone(), buffer.clone()].concat();
+        fft.fft_3d_with_progress(&mut batch, true, Some(&mut |f| reports.push(f))).unwrap();
+        assert_eq!(reports, vec![1.0 / 3.0, 2.0 / 3.0, 2.5 / 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_set_size_keeps_plan_cache() {
+        let mut fft = FFT3D::new(8).unwrap();
+        fft.forward(&[1.0; 512], &[0.0; 512]).unwrap();
+
+        fft.set_size(16).unwrap();
+        assert_eq!((fft.size(), fft.total_size(), fft.dims()), (16, 4096, vec![16, 16, 16]));
+        let mut input = vec![0.0f32; 4096];
+        input[0] = 1.0;
+        let spectrum = fft.forward(&input, &vec![0.0; 4096]).unwrap();
+        assert_eq!(spectrum.len(), 2 * 4096);
+        assert!(spectrum.chunks_exact(2).all(|c| (c[0] - 1.0).abs() < 1e-6 && c[1].abs() < 1e-6));
+
+        // Both sizes stay planned after switching back
+        fft.set_size(8).unwrap();
+        fft.forward(&[1.0; 512], &[0.0; 512]).unwrap();
+        let cache = fft.plan_cache.borrow();
+        assert!(cache.plans.contains_key(&8) && cache.plans.contains_key(&16));
+        assert_eq!(cache.plans.len(), 2);
+    }
+
+    #[test]
+    fn test_fft2d_creation() {
+        let fft = FFT2D::new(16).unwrap();
+        assert_eq!(fft.size(), 16);
+        assert_eq!(fft.total_size(), 256);
+    }
+
+    #[test]
+    fn test_fft2d_invalid_size() {
+        // FFT2D::new validates its side length with validate_size
+        assert_eq!(validate_size(12).unwrap_err().code(), "UNSUPPORTED_SIZE");
+        assert!(FFTPlanCache::new().get_plans(12).is_err());
+        assert!(FFT2D::new(16).is_ok());
+    }
+
+    #[test]
+    fn test_fft2d_norm_modes() {
+        let n = 8;
+        let mut fft = FFT2D::new(n).unwrap();
+        assert_eq!(fft.norm_mode(), NormMode::Backward);
+        let mut real = vec![0.0f32; n * n];
+        let mut imag = vec![0.0f32; n * n];
+        fill_random(&mut real, 41);
+        fill_random(&mut imag, 42);
+        let unscaled = fft.forward(&real, &imag).unwrap();
+
+        for (mode, forward_scale) in [
+            (NormMode::Forward, 1.0 / 64.0),
+            (NormMode::Ortho, 1.0 / 8.0),
+            (NormMode::OrthonormalPerAxis, 1.0 / 8.0),
+        ] {
+            fft.set_norm(mode);
+            let spectrum = fft.forward(&real, &imag).unwrap();
+            for (a, b) in spectrum.iter().zip(unscaled.iter()) {
+                assert!((a - b * forward_scale).abs() < 1e-5);
+            }
+
+            // Every convention still round-trips
+            let spec_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+            let spec_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+            let restored = fft.inverse(&spec_real, &spec_imag).unwrap();
+            for (i, pair) in restored.chunks_exact(2).enumerate() {
+                assert!((pair[0] - real[i]).abs() < 1e-5);
+                assert!((pair[1] - imag[i]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft2d_roundtrip() {
+        let n = 16;
+        let fft = FFT2D::new(n).unwrap();
+        let input_real: Vec<f32> = (0..n * n).map(|i| ((i * 7 % 11) as f32 - 5.0) / 3.0).collect();
+        let input_imag: Vec<f32> = (0..n * n).map(|i| (i % 3) as f32).collect();
+
+        let spectrum = fft.forward(&input_real, &input_imag).unwrap();
+        // DC bin is the plain sum
+        let sum: f32 = input_real.iter().sum();
+        assert!((spectrum[0] - sum).abs() < 1e-3);
+
+        let re: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let im: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        let output = fft.inverse(&re, &im).unwrap();
+        for (i, c) in output.chunks_exact(2).enumerate() {
+            assert!((c[0] - input_real[i]).abs() < 1e-5);
+            assert!((c[1] - input_imag[i]).abs() < 1e-5);
+        }
+
+        // A 2D transform of one slice matches the 3D transform of a volume of
+        // identical slices at kz = 0 (scaled by the slice count)
+        let mut fft3 = FFT3D::new(n).unwrap();
+        let mut fft2 = FFT2D::new(n).unwrap();
+        fft2.share_cache_with(&fft3);
+        let volume_real: Vec<f32> = input_real.iter().cycle().take(n * n * n).copied().collect();
+        let volume_imag: Vec<f32> = input_imag.iter().cycle().take(n * n * n).copied().collect();
+        let volume = fft3.forward(&volume_real, &volume_imag).unwrap();
+        let slice = fft2.forward(&input_real, &input_imag).unwrap();
+        for (a, b) in slice.iter().zip(volume.iter()) {
+            assert!((a * n as f32 - b).abs() < 1e-2);
+        }
+        assert_eq!(fft3.plan_cache.borrow().plans.len(), 1);
+    }
+
+    #[test]
+    fn test_autocorrelation() {
+        let n = 8;
+        let mut fft = FFT3D::new(n).unwrap();
+        let real: Vec<f32> = (0..512).map(|i| ((i * 13 % 7) as f32 - 3.0) / 2.0).collect();
+        let imag: Vec<f32> = (0..512).map(|i| (i % 4) as f32 * 0.25).collect();
+        let energy = calculate_energy(&real, &imag).unwrap();
+
+        for mode in [NormMode::Backward, NormMode::Forward, NormMode::Ortho, NormMode::OrthonormalPerAxis] {
+            fft.set_norm(mode);
+            let acf = fft.autocorrelation(&real, &imag).unwrap();
+            assert_eq!(acf.len(), 512);
+            assert!((acf[0] - energy).abs() < 1e-3 * energy, "{:?}: {} vs {}", mode, acf[0], energy);
+            assert!(acf.iter().all(|&v| v <= acf[0] + 1e-3));
+        }
+    }
+
+    #[test]
+    fn test_forward_axis() {
+        let mut fft = FFT3D::new_dims(4, 8, 16).unwrap();
+        let size = 4 * 8 * 16;
+        let real: Vec<f32> = (0..size).map(|i| ((i * 11 % 23) as f32 - 11.0) / 5.0).collect();
+        let imag: Vec<f32> = (0..size).map(|i| (i % 5) as f32 * 0.1).collect();
+        let split = |v: &[f32]| -> (Vec<f32>, Vec<f32>) {
+            (v.iter().step_by(2).copied().collect(), v.iter().skip(1).step_by(2).copied().collect())
+        };
+
+        // Applying the three axes in turn equals the full 3D transform
+        let (mut re, mut im) = (real.clone(), imag.clone());
+        for axis in 0..3 {
+            (re, im) = split(&fft.forward_axis(&re, &im, axis).unwrap());
+        }
+        let full = fft.forward(&real, &imag).unwrap();
+        for (i, (a, b)) in re.iter().zip(full.iter().step_by(2)).enumerate() {
+            assert!((a - b).abs() < 1e-3, "mismatch at {}: {} vs {}", i, a, b);
+        }
+
+        // A Z-only transform of a field constant along Z puts everything at kz = 0
+        let layer: Vec<f32> = (0..size).map(|i| (i % 32) as f32).collect();
+        let (z_re, z_im) = split(&fft.forward_axis(&layer, &vec![0.0; size], 2).unwrap());
+        assert!((z_re[5] - 16.0 * 5.0).abs() < 1e-4);
+        assert!(z_re[32..].iter().chain(z_im.iter()).all(|v| v.abs() < 1e-4));
+
+        // inverse_axis undoes forward_axis
+        for axis in 0..3 {
+            let (f_re, f_im) = split(&fft.forward_axis(&real, &imag, axis).unwrap());
+            let (b_re, b_im) = split(&fft.inverse_axis(&f_re, &f_im, axis).unwrap());
+            for i in 0..size {
+                assert!((b_re[i] - real[i]).abs() < 1e-5 && (b_im[i] - imag[i]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_soft_threshold() {
+        let fft = FFT3D::new(2).unwrap();
+        let mut real = vec![3.0f32, 0.3, 0.0, -6.0, 1.0, 0.0, 2.0, 0.0];
+        let mut imag = vec![4.0f32, 0.4, 0.0, 8.0, 0.0, -1.0, 0.0, 0.0];
+        fft.soft_threshold(&mut real, &mut imag, 1.0).unwrap();
+
+        // |3+4i| = 5 -> 4 and |-6+8i| = 10 -> 9, with the phase kept
+        assert!((real[0] - 2.4).abs() < 1e-6 && (imag[0] - 3.2).abs() < 1e-6);
+        assert!((real[3] + 5.4).abs() < 1e-6 && (imag[3] - 7.2).abs() < 1e-6);
+        assert!((real[6] - 1.0).abs() < 1e-6);
+
+        // Magnitudes at or below lambda vanish, and zeros stay finite
+        for i in [1, 2, 4, 5, 7] {
+            assert_eq!((real[i], imag[i]), (0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_quantize_roundtrip() {
+        let fft = FFT3D::new(4).unwrap();
+        let real: Vec<f32> = (0..64).map(|i| ((i * 37 % 41) as f32 - 20.0) * 0.731).collect();
+        let imag: Vec<f32> = (0..64).map(|i| ((i * 7 % 13) as f32 - 6.0) * 1.37).collect();
+        let step = 0.01;
+
+        let quantized = fft.quantize(&real, &imag, step).unwrap();
+        assert_eq!(quantized.len(), 128);
+        let restored = fft.dequantize(&quantized, step).unwrap();
+        for (i, c) in restored.chunks_exact(2).enumerate() {
+            assert!((c[0] - real[i]).abs() <= step / 2.0 + 1e-5);
+            assert!((c[1] - imag[i]).abs() <= step / 2.0 + 1e-5);
+        }
+
+        // Out-of-range values saturate instead of wrapping
+        let mut big = vec![0.0f32; 64];
+        big[0] = 1e9;
+        big[1] = -1e9;
+        let saturated = fft.quantize(&big, &vec![0.0; 64], 1.0).unwrap();
+        assert_eq!((saturated[0], saturated[2]), (i16::MAX, i16::MIN));
+    }
+
+    #[test]
+    fn test_benchmark() {
+        let ms = benchmark(8, 5).unwrap();
+        assert!(ms.is_finite() && ms >= 0.0);
+    }
+
+    #[test]
+    fn test_dct3d_roundtrip() {
+        let n = 8;
+        let dct = DCT3D::new(n).unwrap();
+        let input: Vec<f32> = (0..512).map(|i| ((i * 29 % 31) as f32 - 15.0) / 7.0).collect();
+
+        let coefficients = dct.forward(&input).unwrap();
+        let output = dct.inverse(&coefficients).unwrap();
+        for (i, (&a, &b)) in output.iter().zip(input.iter()).enumerate() {
+            assert!((a - b).abs() < 1e-4, "mismatch at {}: {} vs {}", i, a, b);
+        }
+
+        // Matches the direct DCT-II sum along X for a field varying only in X
+        let ramp: Vec<f32> = (0..512).map(|i| (i % n) as f32).collect();
+        let ramp_coefficients = dct.forward(&ramp).unwrap();
+        for (k, &coefficient) in ramp_coefficients[..n].iter().enumerate() {
+            let direct: f32 = (0..n)
+                .map(|x| x as f32 * (std::f32::consts::PI * (x as f32 + 0.5) * k as f32 / n as f32).cos())
+                .sum();
+            assert!((coefficient - direct * 64.0).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_dct3d_constant_is_dc_only() {
+        let dct = DCT3D::new(8).unwrap();
+        let coefficients = dct.forward(&[2.5; 512]).unwrap();
+        assert!((coefficients[0] - 2.5 * 512.0).abs() < 1e-2);
+        assert!(coefficients[1..].iter().all(|v| v.abs() < 1e-3));
+    }
+
+    #[test]
+    fn test_strict_validation() {
+        let mut fft = FFT3D::new(4).unwrap();
+        let real = vec![1.0f32; 64];
+        let mut imag = vec![0.0f32; 64];
+        imag[17] = f32::NAN;
+
+        // Off by default: the NaN passes validation
+        assert!(!fft.strict_validation());
+        assert!(fft.validate_input(&real, &imag).is_ok());
+
+        fft.set_strict_validation(true);
+        let error = fft.validate_input(&real, &imag).unwrap_err();
+        assert_eq!(error, FftError::NonFinite { index: 17 });
+        assert_eq!(error.code(), "NON_FINITE");
+        assert_eq!(error.to_string(), "Non-finite input value at index 17");
+
+        let mut bad_real = real.clone();
+        bad_real[3] = f32::NEG_INFINITY;
+        assert_eq!(fft.validate_real(&bad_real), Err(FftError::NonFinite { index: 3 }));
+        assert!(fft.validate_input(&real, &vec![0.0; 64]).is_ok());
+    }
+
+    #[test]
+    fn test_forward_timed() {
+        let n = 16;
+        let mut fft = FFT3D::new(n).unwrap();
+        let mut real = vec![0.0f32; n * n * n];
+        fill_random(&mut real, 7);
+        let imag = vec![0.0f32; n * n * n];
+
+        let timed = fft.forward_timed(&real, &imag).unwrap();
+        assert_eq!(timed.spectrum(), fft.forward(&real, &imag).unwrap());
+        for ms in [timed.x_pass_ms(), timed.y_pass_ms(), timed.z_pass_ms()] {
+            assert!(ms.is_finite() && ms >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_gradient_of_sinusoid() {
+        use std::f32::consts::PI;
+
+        let (nx, ny, nz) = (8, 16, 32);
+        let mut fft = FFT3D::new_dims(nx, ny, nz).unwrap();
+        let size = nx * ny * nz;
+        let coords = |i: usize| ((i % nx) as f32, ((i / nx) % ny) as f32, (i / (nx * ny)) as f32);
+        // f = sin(2π·2y/ny) + cos(2π·3z/nz) + 4
+        let real: Vec<f32> = (0..size)
+            .map(|i| {
+                let (_, y, z) = coords(i);
+                (2.0 * PI * 2.0 * y / ny as f32).sin() + (2.0 * PI * 3.0 * z / nz as f32).cos() + 4.0
+            })
+            .collect();
+        let imag = vec![0.0f32; size];
+
+        let dx = fft.gradient(&real, &imag, 0).unwrap();
+        assert!(dx.iter().all(|v| v.abs() < 1e-4));
+
+        let dy = fft.gradient(&real, &imag, 1).unwrap();
+        let dz = fft.gradient(&real, &imag, 2).unwrap();
+        for i in 0..size {
+            let (_, y, z) = coords(i);
+            let wy = 2.0 * PI * 2.0 / ny as f32;
+            let wz = 2.0 * PI * 3.0 / nz as f32;
+            assert!((dy[2 * i] - wy * (wy * y).cos()).abs() < 1e-4, "dy at {}", i);
+            assert!((dz[2 * i] + wz * (wz * z).sin()).abs() < 1e-4, "dz at {}", i);
+            assert!(dy[2 * i + 1].abs() < 1e-4 && dz[2 * i + 1].abs() < 1e-4);
+        }
+
+        // A pure Nyquist oscillation has a zero derivative
+        let nyquist: Vec<f32> = (0..size).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+        let d = fft.gradient(&nyquist, &imag, 0).unwrap();
+        assert!(d.iter().all(|v| v.abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_laplacian_eigenvalue() {
+        use std::f32::consts::PI;
+
+        let n = 16;
+        let mut fft = FFT3D::new(n).unwrap();
+        let size = n * n * n;
+        let (kx, ky, kz) = (1.0, 3.0, 2.0);
+        let mode: Vec<f32> = (0..size)
+            .map(|i| {
+                let (x, y, z) = ((i % n) as f32, ((i / n) % n) as f32, (i / (n * n)) as f32);
+                (2.0 * PI * (kx * x + ky * y + kz * z) / n as f32).sin()
+            })
+            .collect();
+        let imag = vec![0.0f32; size];
+
+        let eigenvalue = -(2.0 * PI / n as f32).powi(2) * (kx * kx + ky * ky + kz * kz);
+        let result = fft.laplacian(&mode, &imag).unwrap();
+        for (i, c) in result.chunks_exact(2).enumerate() {
+            assert!((c[0] - eigenvalue * mode[i]).abs() < 1e-4, "mismatch at {}", i);
+            assert!(c[1].abs() < 1e-4);
+        }
+
+        // Constants are in the null space
+        let constant = fft.laplacian(&vec![3.0; size], &imag).unwrap();
+        assert!(constant.iter().all(|v| v.abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_is_hermitian() {
+        let mut fft = FFT3D::new_dims(4, 8, 2).unwrap();
+        let mut real = vec![0.0f32; 64];
+        fill_random(&mut real, 3);
+        let spectrum = fft.forward(&real, &vec![0.0; 64]).unwrap();
+        let mut re: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let mut im: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        assert!(fft.is_hermitian(&re, &im, 1e-5).unwrap());
+
+        // Breaking one coefficient breaks the symmetry with its mirror
+        im[5] += 0.1;
+        assert!(!fft.is_hermitian(&re, &im, 1e-5).unwrap());
+        assert!(fft.is_hermitian(&re, &im, 0.2).unwrap());
+
+        // A self-mirrored bin (DC) must be real
+        im[5] -= 0.1;
+        re[0] += 1.0;
+        assert!(fft.is_hermitian(&re, &im, 1e-5).unwrap());
+        im[0] = 0.5;
+        assert!(!fft.is_hermitian(&re, &im, 1e-5).unwrap());
+    }
+
+    #[test]
+    fn test_forward_in_place() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut data = vec![0.0f32; 1024];
+        fill_random(&mut data, 11);
+
+        let mut expected = vec![0.0f32; 1024];
+        fft.forward_into(&data, &mut expected).unwrap();
+
+        fft.forward_in_place(&mut data).unwrap();
+        assert_eq!(data, expected);
+
+        // The work buffer is reused rather than reallocated
+        let capacity = fft.work.capacity();
+        let pointer = fft.work.as_ptr();
+        fft.forward_in_place(&mut data).unwrap();
+        assert_eq!((fft.work.capacity(), fft.work.as_ptr()), (capacity, pointer));
+    }
+
+    #[test]
+    fn test_normalize_kernel() {
+        let mut fft = FFT3D::new(4).unwrap();
+        let mut real: Vec<f32> = (0..64).map(|i| (i % 5) as f32 * 0.5).collect();
+        let mut imag = vec![0.25f32; 64];
+        let before: f32 = real.iter().sum();
+
+        fft.normalize_kernel(&mut real, &mut imag).unwrap();
+        assert!((real.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        assert!((imag[0] - 0.25 / before).abs() < 1e-7);
+
+        // The normalized kernel's spectrum has a unit DC coefficient
+        let spectrum = fft.forward(&real, &vec![0.0; 64]).unwrap();
+        assert!((spectrum[0] - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_inverse_real_out() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        fill_random(&mut real, 5);
+        let spectrum = fft.forward(&real, &vec![0.0; 512]).unwrap();
+        let re: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let im: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+
+        let full = fft.inverse(&re, &im).unwrap();
+        let real_only = fft.inverse_real_out(&re, &im).unwrap();
+        assert_eq!(real_only.len(), 512);
+        assert_eq!(real_only, full.iter().step_by(2).copied().collect::<Vec<_>>());
+        for (a, b) in real_only.iter().zip(real.iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_supported_sizes() {
+        assert!(FFT3D::new(2).is_ok());
+        assert!(FFT3D::new(128).is_ok());
+        assert!(!is_supported_size(1));
+        assert!(!is_supported_size(48));
+        assert!(!is_supported_size(512));
+        assert_eq!(supported_size_iter().collect::<Vec<_>>(), vec![2, 4, 8, 16, 32, 64, 128, 256]);
+        assert_eq!(supported_sizes(), vec![2, 4, 8, 16, 32, 64, 128, 256]);
+        assert!(supported_sizes().iter().all(|&n| FFT3D::new(n as usize).is_ok()));
+    }
+
+    #[test]
+    fn test_forward_of_magnitude() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let real = vec![3.0f32; 512];
+        let mut imag = vec![4.0f32; 512];
+        imag[100] = -4.0;
+
+        // |field| is 5 everywhere regardless of phase
+        let spectrum = fft.forward_of_magnitude(&real, &imag).unwrap();
+        assert!((spectrum[0] - 2560.0).abs() < 1e-2);
+        assert!(spectrum[2..].iter().all(|v| v.abs() < 1e-2));
+    }
+
+    #[test]
+    fn test_forward_full() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 101);
+        fill_random(&mut imag, 102);
+
+        let result = fft.forward_full(&real, &imag).unwrap();
+        let spectrum = fft.forward(&real, &imag).unwrap();
+        assert_eq!(result.spectrum(), spectrum);
+
+        let fwd_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let fwd_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        let energy = calculate_energy(&fwd_real, &fwd_imag).unwrap();
+        assert!((result.energy() - energy).abs() < 1e-4 * energy);
+
+        let magnitude = result.magnitude();
+        assert_eq!(magnitude.len(), 512);
+        assert!((magnitude[3] - fwd_real[3].hypot(fwd_imag[3])).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fft_result_to_polar() {
+        let mut fft = FFT3D::new(4).unwrap();
+        let mut real = vec![0.0f32; 64];
+        fill_random(&mut real, 103);
+        let imag = vec![0.0f32; 64];
+
+        let result = fft.forward_full(&real, &imag).unwrap();
+        let polar = result.to_polar();
+        assert!(polar.magnitude.get().is_none() && polar.phase.get().is_none());
+
+        let magnitude = polar.magnitude();
+        let phase = polar.phase();
+        assert_eq!(magnitude, result.magnitude());
+        assert_eq!(phase.len(), 64);
+        assert!(polar.phase.get().is_some());
+        for (c, (m, p)) in result.spectrum().chunks_exact(2).zip(magnitude.iter().zip(&phase)) {
+            assert!((m * p.cos() - c[0]).abs() < 1e-4);
+            assert!((m * p.sin() - c[1]).abs() < 1e-4);
+        }
+
+        // A magnitude already cached on the result is reused
+        assert!(result.to_polar().magnitude.get().is_some());
+    }
+
+    #[test]
+    fn test_forward_field_matches_forward() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let real: Vec<f32> = (0..512).map(|i| (i % 7) as f32).collect();
+        let imag: Vec<f32> = (0..512).map(|i| (i % 3) as f32 * 0.5).collect();
+
+        let field = ComplexField::new(&real, &imag).unwrap();
+        assert_eq!(field.length(), 512);
+
+        let expected = fft.forward(&real, &imag).unwrap();
+        let actual = fft.forward_field(&field).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_last_transform_flops() {
+        let mut fft = FFT3D::new(16).unwrap();
+        assert_eq!(fft.last_transform_flops(), 0.0);
+
+        let real = vec![1.0f32; 4096];
+        let imag = vec![0.0f32; 4096];
+
+        // Plain transforms skip the clock unless timing is recorded
+        fft.forward(&real, &imag).unwrap();
+        assert_eq!(fft.last_transform_ms(), 0.0);
+
+        fft.set_record_timing(true);
+        assert!(fft.record_timing());
+        fft.forward(&real, &imag).unwrap();
+        assert!(fft.last_transform_ms() > 0.0);
+        assert!(fft.last_transform_flops() > 0.0);
+    }
+
+    #[test]
+    fn test_forward_into_complex() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 41);
+        fill_random(&mut imag, 42);
+
+        let input: Vec<Complex<f32>> = real.iter().zip(imag.iter()).map(|(&re, &im)| Complex::new(re, im)).collect();
+        let mut output = vec![Complex::new(0.0f32, 0.0f32); 512];
+        fft.forward_into_complex(&input, &mut output).unwrap();
+
+        let expected = fft.forward(&real, &imag).unwrap();
+        for (i, c) in output.iter().enumerate() {
+            assert_eq!(c.re, expected[2 * i]);
+            assert_eq!(c.im, expected[2 * i + 1]);
+        }
+    }
+
+    #[test]
+    fn test_forward_tiled_matches_forward() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let (n, t) = (8, 4);
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 1);
+        fill_random(&mut imag, 2);
+
+        // Split the volume into 2x2x2 tiles of 4^3
+        let mut tiles = Vec::with_capacity(1024);
+        for tz in 0..2 {
+            for ty in 0..2 {
+                for tx in 0..2 {
+                    for z in 0..t {
+                        for y in 0..t {
+                            for x in 0..t {
+                                let i = (tz * t + z) * n * n + (ty * t + y) * n + tx * t + x;
+                                tiles.push(real[i]);
+                                tiles.push(imag[i]);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let expected = fft.forward(&real, &imag).unwrap();
+        assert_eq!(fft.forward_tiled(&tiles, 2).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_flush_subnormals() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        let imag = vec![0.0f32; 512];
+        real[0] = 1e-39;
+
+        // An impulse spreads its subnormal amplitude to every coefficient
+        let kept = fft.forward(&real, &imag).unwrap();
+        assert!(kept[2].is_subnormal());
+
+        fft.set_flush_subnormals(true);
+        assert!(fft.flush_subnormals());
+        let flushed = fft.forward(&real, &imag).unwrap();
+        assert!(flushed.iter().all(|&v| v == 0.0));
+
+        // So do the half-spectrum variants
+        assert!(fft.forward_real(&real).unwrap().iter().all(|&v| v == 0.0));
+        let mut half_spectrum = vec![0.0f32; 2 * 8 * 8 * 5];
+        half_spectrum[0] = 512.0 * 1e-39;
+        assert!(fft.inverse_real(&half_spectrum).unwrap().iter().all(|&v| v == 0.0));
+
+        let mut spectrum_real = vec![0.0f32; 512];
+        spectrum_real[0] = 512.0 * 1e-39;
+        assert!(fft.inverse_real_spectrum(&spectrum_real).unwrap().iter().all(|&v| v == 0.0));
+
+        // The zero-allocation variants post-process exactly like forward/inverse
+        let mut data = vec![0.0f32; 1024];
+        data[0] = 1e-39;
+        let mut output = vec![1.0f32; 1024];
+        fft.forward_into(&data, &mut output).unwrap();
+        assert_eq!(output, flushed);
+        fft.forward_in_place(&mut data).unwrap();
+        assert_eq!(data, flushed);
+
+        let mut spectrum_real = vec![0.0f32; 512];
+        spectrum_real[0] = 512.0 * 1e-39;
+        fft.inverse_into(&spectrum_real, &imag, &mut output).unwrap();
+        assert!(output.iter().all(|&v| v == 0.0));
+
+        // As do the other entry points that return a transformed volume
+        let full = fft.forward_full(&real, &imag).unwrap();
+        assert_eq!(full.spectrum(), flushed);
+        assert_eq!(full.energy(), 0.0);
+        assert!(fft.forward_of_magnitude(&real, &imag).unwrap().iter().all(|&v| v == 0.0));
+        // A single tile is the interleaved volume itself
+        let mut tile = vec![0.0f32; 1024];
+        tile[0] = 1e-39;
+        assert_eq!(fft.forward_tiled(&tile, 1).unwrap(), flushed);
+        let ones = vec![1.0f32; 512];
+        let weighted = fft.inverse_weighted(&spectrum_real, &imag, &ones).unwrap();
+        assert!(weighted.iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_real_mode_inverse() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        fill_random(&mut real, 7);
+        let zeros = vec![0.0f32; 512];
+
+        let spectrum = fft.forward(&real, &zeros).unwrap();
+        let mut spec_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let mut spec_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+
+        // Break the symmetry of one pair; real mode must still produce a real field
+        spec_real[1] += 5.0;
+        spec_imag[1] += 3.0;
+
+        fft.set_real_mode(true);
+        assert!(fft.real_mode());
+        let output = fft.inverse(&spec_real, &spec_imag).unwrap();
+        assert_eq!(output.len(), 512);
+
+        // Matches the complex inverse of the symmetrized spectrum
+        spec_real[1] -= 2.5;
+        spec_imag[1] -= 1.5;
+        spec_real[7] += 2.5;
+        spec_imag[7] -= 1.5;
+        fft.set_real_mode(false);
+        let reference = fft.inverse(&spec_real, &spec_imag).unwrap();
+        for (i, &v) in output.iter().enumerate() {
+            assert!((v - reference[2 * i]).abs() < 1e-4);
+            assert!(reference[2 * i + 1].abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_forward_real_half_spectrum_roundtrip() {
+        let n = 8;
+        let mut fft = FFT3D::new(n).unwrap();
+        let mut real = vec![0.0f32; 512];
+        fill_random(&mut real, 91);
+
+        let half = fft.forward_real(&real).unwrap();
+        assert_eq!(half.len(), 2 * n * n * (n / 2 + 1));
+
+        // Matches the kept coefficients of the full spectrum
+        let full = fft.forward(&real, &vec![0.0f32; 512]).unwrap();
+        let (kx, ky, kz) = (3, 5, 6);
+        let h = 2 * ((kz * n + ky) * (n / 2 + 1) + kx);
+        let f = 2 * (kz * n * n + ky * n + kx);
+        assert_eq!(&half[h..h + 2], &full[f..f + 2]);
+
+        let restored = fft.inverse_real(&half).unwrap();
+        for (a, b) in restored.iter().zip(real.iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_inverse_real_spectrum() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut spectrum = vec![0.0f32; 512];
+        fill_random(&mut spectrum, 3);
+        let zeros = vec![0.0f32; 512];
+
+        let expected = fft.inverse(&spectrum, &zeros).unwrap();
+        let output = fft.inverse_real_spectrum(&spectrum).unwrap();
+        assert_eq!(output.len(), 512);
+        for (i, &v) in output.iter().enumerate() {
+            assert_eq!(v, expected[2 * i]);
+        }
+    }
+
+    #[test]
+    fn test_inverse_mapped() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 12);
+        fill_random(&mut imag, 13);
+
+        let expected = fft.inverse(&real, &imag).unwrap();
+        let mapped = fft.inverse_mapped(&real, &imag, 2.0, 0.5).unwrap();
+        assert_eq!(mapped.len(), 512);
+        for (i, &v) in mapped.iter().enumerate() {
+            assert!((v - (expected[2 * i] * 2.0 + 0.5)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_local_spectrum() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let parent = 16;
+        let mut data = vec![0.0f32; 2 * parent * parent * parent];
+        fill_random(&mut data, 91);
+
+        // An interior center with a rectangular window is the plain region transform
+        let local = fft.local_spectrum(&data, parent, 8, 9, 10, Window::Rectangular).unwrap();
+        let mut region = data.clone();
+        fft.forward_in_place_region(&mut region, parent, 4, 5, 6).unwrap();
+        for i in 0..512 {
+            let p = 2 * (((6 + i / 64) * parent + 5 + (i / 8) % 8) * parent + 4 + i % 8);
+            assert_eq!(&local[2 * i..2 * i + 2], &region[p..p + 2]);
+        }
+
+        // Near a corner the block clamps to the face, then is windowed
+        let local = fft.local_spectrum(&data, parent, 0, 15, 2, Window::Hann).unwrap();
+        let w = Window::Hann.coefficients(8);
+        let clamp = |c: usize, k: usize| (c + k).saturating_sub(4).min(parent - 1);
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        for i in 0..512 {
+            let (x, y, z) = (i % 8, (i / 8) % 8, i / 64);
+            let p = 2 * ((clamp(2, z) * parent + clamp(15, y)) * parent + clamp(0, x));
+            let weight = w[x] * w[y] * w[z];
+            real[i] = data[p] * weight;
+            imag[i] = data[p + 1] * weight;
+        }
+        let expected = fft.forward(&real, &imag).unwrap();
+        for (a, b) in local.iter().zip(expected.iter()) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_forward_in_place_region() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let parent = 16;
+        let mut data = vec![0.0f32; 2 * parent * parent * parent];
+        fill_random(&mut data, 21);
+        let original = data.clone();
+        let (ox, oy, oz) = (4, 8, 2);
+
+        // Reference: transform the extracted region with the plain API
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        for i in 0..512 {
+            let p = ((oz + i / 64) * parent * parent + (oy + (i / 8) % 8) * parent + ox + i % 8) * 2;
+            real[i] = original[p];
+            imag[i] = original[p + 1];
+        }
+        let expected = fft.forward(&real, &imag).unwrap();
+
+        fft.forward_in_place_region(&mut data, parent, ox, oy, oz).unwrap();
+        for i in 0..512 {
+            let p = ((oz + i / 64) * parent * parent + (oy + (i / 8) % 8) * parent + ox + i % 8) * 2;
+            assert_eq!(data[p], expected[2 * i]);
+            assert_eq!(data[p + 1], expected[2 * i + 1]);
+        }
+
+        // Voxels outside the region are untouched
+        assert_eq!(data[0], original[0]);
+        assert_eq!(data[data.len() - 1], original[original.len() - 1]);
+    }
+
+    #[test]
+    fn test_forward_batch_matches_individual() {
+        let mut fft = FFT3D::new_dims(8, 4, 2).unwrap();
+        let mut data = vec![0.0f32; 3 * 128];
+        fill_random(&mut data, 121);
+
+        let batch = fft.forward_batch(&data, 3).unwrap();
+        assert_eq!(batch.len(), data.len());
+        for (volume, spectrum) in data.chunks_exact(128).zip(batch.chunks_exact(128)) {
+            assert_eq!(fft.forward_interleaved(volume).unwrap(), spectrum);
+        }
+    }
+
+    #[test]
+    fn test_interleaved_matches_split() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 61);
+        fill_random(&mut imag, 62);
+        let interleaved: Vec<f32> = real.iter().zip(imag.iter()).flat_map(|(&re, &im)| [re, im]).collect();
+
+        let spectrum = fft.forward_interleaved(&interleaved).unwrap();
+        assert_eq!(spectrum, fft.forward(&real, &imag).unwrap());
+
+        let restored = fft.inverse_interleaved(&spectrum).unwrap();
+        for (a, b) in restored.iter().zip(interleaved.iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_forward_into_matches_forward() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut input = vec![0.0f32; 1024];
+        fill_random(&mut input, 81);
+
+        let expected = fft.forward_interleaved(&input).unwrap();
+        let mut output = vec![0.0f32; 1024];
+        fft.forward_into(&input, &mut output).unwrap();
+        assert_eq!(expected, output);
+
+        // Second call reuses the work buffer without growing it
+        let capacity = fft.work.capacity();
+        fft.forward_into(&input, &mut output).unwrap();
+        assert_eq!(expected, output);
+        assert_eq!(fft.work.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_inverse_into_matches_inverse() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let real: Vec<f32> = (0..512).map(|i| (i % 5) as f32).collect();
+        let imag: Vec<f32> = (0..512).map(|i| (i % 3) as f32).collect();
+
+        let expected = fft.inverse(&real, &imag).unwrap();
+        let mut output = vec![0.0f32; 1024];
+        fft.inverse_into(&real, &imag, &mut output).unwrap();
+        assert_eq!(expected, output);
+
+        // Second call reuses the work buffer and gives the same result
+        fft.inverse_into(&real, &imag, &mut output).unwrap();
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_inverse_weighted() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let real: Vec<f32> = (0..512).map(|i| (i % 5) as f32).collect();
+        let imag: Vec<f32> = (0..512).map(|i| (i % 3) as f32).collect();
+
+        // Unit weights match the plain inverse
+        let ones = vec![1.0f32; 512];
+        let expected = fft.inverse(&real, &imag).unwrap();
+        let weighted = fft.inverse_weighted(&real, &imag, &ones).unwrap();
+        for (a, b) in expected.iter().zip(weighted.iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        // Keeping only the DC coefficient yields a constant field
+        let mut dc_only = vec![0.0f32; 512];
+        dc_only[0] = 1.0;
+        let result = fft.inverse_weighted(&real, &imag, &dc_only).unwrap();
+        for c in result.chunks_exact(2) {
+            assert!((c[0] - real[0] / 512.0).abs() < 1e-6);
+            assert!((c[1] - imag[0] / 512.0).abs() < 1e-6);
+        }
+
+        // Real-field mode returns the same real parts as `inverse`
+        fft.set_real_mode(true);
+        let expected = fft.inverse(&real, &imag).unwrap();
+        let weighted = fft.inverse_weighted(&real, &imag, &ones).unwrap();
+        assert_eq!(weighted.len(), 512);
+        for (a, b) in expected.iter().zip(weighted.iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_shift_subvoxel() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let n = 8;
+        let wave = |shift: f32| -> Vec<f32> {
+            (0..512)
+                .map(|i| {
+                    let x = (i % n) as f32 - shift;
+                    (2.0 * std::f32::consts::PI * x / n as f32).cos()
+                })
+                .collect()
+        };
+
+        // A fractional shift of a band-limited wave is exact
+        let shifted = fft.shift_subvoxel(&wave(0.0), 0.25, 0.0, 0.0).unwrap();
+        for (a, b) in shifted.iter().zip(wave(0.25).iter()) {
+            assert!((a - b).abs() < 1e-4, "Expected {}, got {}", b, a);
+        }
+
+        // Integer shifts wrap around the boundary
+        let mut impulse = vec![0.0f32; 512];
+        impulse[7] = 1.0;
+        let wrapped = fft.shift_subvoxel(&impulse, 1.0, 0.0, 0.0).unwrap();
+        assert!((wrapped[0] - 1.0).abs() < 1e-4);
+        assert!(wrapped[7].abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_normalized_magnitude_spectrum() {
+        let real = vec![2.0, 3.0, 0.0];
+        let imag = vec![0.0, 4.0, -1.0];
+
+        let normalized = normalized_magnitude_spectrum(&real, &imag).unwrap();
+        assert_eq!(normalized, vec![1.0, 2.5, 0.5]);
+
+        // Scaling the field leaves the result unchanged
+        let scaled_real: Vec<f32> = real.iter().map(|v| v * 10.0).collect();
+        let scaled_imag: Vec<f32> = imag.iter().map(|v| v * 10.0).collect();
+        assert_eq!(normalized_magnitude_spectrum(&scaled_real, &scaled_imag).unwrap(), normalized);
+    }
+
+    #[test]
+    fn test_blend_spectra() {
+        let spectra = vec![1.0, 2.0, 3.0, 4.0, 10.0, 20.0, 30.0, 40.0];
+        let weights = vec![0.5, 0.25];
+
+        let blended = blend_spectra(&spectra, &weights, 2).unwrap();
+        assert_eq!(blended, vec![3.0, 6.0, 9.0, 12.0]);
+    }
+
+    #[test]
+    fn test_spectral_entropy() {
+        let mut real = vec![0.0f32; 64];
+        let imag = vec![0.0f32; 64];
+        assert_eq!(spectral_entropy(&real, &imag).unwrap(), 0.0);
+
+        real[5] = 3.0;
+        assert_eq!(spectral_entropy(&real, &imag).unwrap(), 0.0);
+
+        let flat_real = vec![1.0f32; 64];
+        let flat_imag = vec![-1.0f32; 64];
+        assert!((spectral_entropy(&flat_real, &flat_imag).unwrap() - 6.0).abs() < 1e-4);
+
+        // Two equal peaks carry one bit
+        real[9] = -3.0;
+        assert!((spectral_entropy(&real, &imag).unwrap() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_spectrum_sparsity() {
+        let real = vec![10.0, 0.0, 0.5, 6.0];
+        let imag = vec![0.0, 0.0, 0.0, 8.0];
+
+        // Peak magnitude is 10; 0 and 0.5 are below 10%
+        assert_eq!(spectrum_sparsity(&real, &imag, 0.1).unwrap(), 0.5);
+        assert_eq!(spectrum_sparsity(&real, &imag, 0.0).unwrap(), 0.0);
+
+        let zeros = vec![0.0; 4];
+        assert_eq!(spectrum_sparsity(&zeros, &zeros, 0.1).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_effective_mode_count() {
+        // Two equal modes and one weak mode
+        let real = vec![2.0, 0.0, 2.0, 0.1];
+        let imag = vec![0.0, 0.0, 0.0, 0.0];
+
+        let result = effective_mode_count(&real, &imag, 0.5).unwrap();
+        assert_eq!(result[0], 2.0);
+        assert!((result[1] - 2.0).abs() < 1e-2);
+
+        let zeros = vec![0.0; 4];
+        assert_eq!(effective_mode_count(&zeros, &zeros, 0.5).unwrap(), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_project_axis() {
+        let fft = FFT3D::new(8).unwrap();
+        let n = 8;
+
+        // Field equal to its X coordinate
+        let field: Vec<f32> = (0..512).map(|i| (i % n) as f32).collect();
+
+        // Summing along X gives 0+1+...+7 everywhere
+        let along_x = fft.project_axis(&field, 0).unwrap();
+        assert_eq!(along_x.len(), 64);
+        assert!(along_x.iter().all(|&v| (v - 28.0).abs() < 1e-5));
+
+        // Summing along Z gives 8 * x, indexed [y][x]
+        let along_z = fft.project_axis(&field, 2).unwrap();
+        for (i, &v) in along_z.iter().enumerate() {
+            assert!((v - 8.0 * (i % n) as f32).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_forward_then_slice_matches_full_spectrum() {
+        let n = 8;
+        let mut fft = FFT3D::new(n).unwrap();
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 3);
+        fill_random(&mut imag, 4);
+
+        let full = fft.forward(&real, &imag).unwrap();
+        for axis in 0..3 {
+            let slice = fft.forward_then_slice(&real, &imag, axis, 5).unwrap();
+            assert_eq!(slice.len(), 2 * n * n);
+            for a in 0..n {
+                for b in 0..n {
+                    let (x, y, z) = match axis {
+                        0 => (5, b, a),
+                        1 => (b, 5, a),
+                        _ => (b, a, 5),
+                    };
+                    let i = z * n * n + y * n + x;
+                    let j = a * n + b;
+                    assert!((slice[2 * j] - full[2 * i]).abs() < 1e-4);
+                    assert!((slice[2 * j + 1] - full[2 * i + 1]).abs() < 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_dims_non_cubic() {
+        let (nx, ny, nz) = (8, 4, 16);
+        let total = nx * ny * nz;
+        let mut fft = FFT3D::new_dims(nx, ny, nz).unwrap();
+        assert_eq!(fft.total_size(), total);
+        assert_eq!(fft.dims(), vec![8, 4, 16]);
+
+        let mut real = vec![0.0f32; total];
+        let mut imag = vec![0.0f32; total];
+        fill_random(&mut real, 41);
+        fill_random(&mut imag, 42);
+        let output = fft.forward(&real, &imag).unwrap();
+
+        // Reference: 1D transforms along each axis with explicit strides
+        let mut reference: Vec<Complex<f32>> =
+            real.iter().zip(imag.iter()).map(|(&re, &im)| Complex::new(re, im)).collect();
+        let mut planner = FftPlanner::new();
+        for (n, stride) in [(nx, 1), (ny, nx), (nz, nx * ny)] {
+            let plan = planner.plan_fft_forward(n);
+            for start in (0..total).filter(|i| (i / stride) % n == 0) {
+                let mut line: Vec<Complex<f32>> = (0..n).map(|k| reference[start + k * stride]).collect();
+                plan.process(&mut line);
+                for (k, c) in line.iter().enumerate() {
+                    reference[start + k * stride] = *c;
+                }
+            }
+        }
+        for (i, c) in reference.iter().enumerate() {
+            assert!((output[2 * i] - c.re).abs() < 1e-4, "Real mismatch at {}", i);
+            assert!((output[2 * i + 1] - c.im).abs() < 1e-4, "Imag mismatch at {}", i);
+        }
+
+        let fwd_real: Vec<f32> = output.iter().step_by(2).copied().collect();
+        let fwd_imag: Vec<f32> = output.iter().skip(1).step_by(2).copied().collect();
+        let inverse = fft.inverse(&fwd_real, &fwd_imag).unwrap();
+        for (i, &re) in real.iter().enumerate() {
+            assert!((inverse[2 * i] - re).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_share_cache_with() {
+        let mut a = FFT3D::new(8).unwrap();
+        let mut b = FFT3D::new(16).unwrap();
+        b.share_cache_with(&a);
+        assert!(Rc::ptr_eq(&a.plan_cache, &b.plan_cache));
 
-/// Get version information
-#[wasm_bindgen]
-pub fn get_version() -> String {
-    env!("CARGO_PKG_VERSION").to_string()
-}
+        let real = vec![1.0f32; 4096];
+        let imag = vec![0.0f32; 4096];
+        let spectrum = b.forward(&real, &imag).unwrap();
+        assert!((spectrum[0] - 4096.0).abs() < 1e-2);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // b's plan is visible through a's handle
+        assert!(a.plan_cache.borrow().plans.contains_key(&16));
+        assert!(!a.plan_cache.borrow().plans.contains_key(&8));
+        a.forward(&real[..512], &imag[..512]).unwrap();
+        assert!(b.plan_cache.borrow().plans.contains_key(&8));
+    }
 
     #[test]
-    fn test_fft_creation() {
-        let fft = FFT3D::new(8);
-        assert!(fft.is_ok());
+    fn test_group_delay_of_shifted_impulse() {
+        let mut fft = FFT3D::new(8).unwrap();
 
-        let fft = fft.unwrap();
-        assert_eq!(fft.size(), 8);
-        assert_eq!(fft.total_size(), 512);
+        // Impulse delayed by 3 voxels along Y
+        let mut real = vec![0.0f32; 512];
+        let imag = vec![0.0f32; 512];
+        real[3 * 8] = 1.0;
+
+        let spectrum = fft.forward(&real, &imag).unwrap();
+        let spec_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let spec_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+
+        let delay_y = group_delay_axis(&spec_real, &spec_imag, 8, 1).unwrap();
+        assert!(delay_y.iter().all(|&d| (d - 3.0).abs() < 1e-3));
+
+        let delay_x = group_delay_axis(&spec_real, &spec_imag, 8, 0).unwrap();
+        assert!(delay_x.iter().all(|&d| d.abs() < 1e-3));
     }
 
     #[test]
-    fn test_fft_invalid_size() {
-        let fft = FFT3D::new(5);
-        assert!(fft.is_err());
+    fn test_aliasing_risk() {
+        let n = 16;
+        let mut real = vec![0.0f32; 4096];
+        let imag = vec![0.0f32; 4096];
+        real[0] = 1.0;
+        // Frequency 3 along Y is safe for an 8^3 target, frequency -5 along Z is not
+        real[3 * n] = 1.0;
+        real[(n - 5) * n * n] = 1.0;
+        real[(n - 5) * n * n + 1] = 1.0;
+
+        assert_eq!(aliasing_risk(&real, &imag, n, 8).unwrap(), 0.5);
+        assert_eq!(aliasing_risk(&real, &imag, n, 16).unwrap(), 0.0);
     }
 
     #[test]
-    fn test_forward_inverse_roundtrip() {
+    fn test_marginal_energy() {
+        let n = 8;
+        let mut real = vec![0.0f32; 512];
+        let imag = vec![0.0f32; 512];
+        // Coefficient at (x=1, y=2, z=3)
+        real[3 * n * n + 2 * n + 1] = 2.0;
+
+        let marginals = marginal_energy(&real, &imag, n).unwrap();
+        assert_eq!(marginals.len(), 24);
+        assert_eq!(marginals[1], 4.0);
+        assert_eq!(marginals[n + 2], 4.0);
+        assert_eq!(marginals[2 * n + 3], 4.0);
+        assert_eq!(marginals.iter().sum::<f32>(), 12.0);
+    }
+
+    #[test]
+    fn test_reorder_by_frequency_roundtrip() {
+        let n = 8;
+        let order = frequency_order(n).unwrap();
+        assert_eq!(order.len(), 512);
+        // DC first, then the six unit-frequency neighbours
+        assert_eq!(order[0], 0);
+        let mut first_shell: Vec<u32> = order[1..7].to_vec();
+        first_shell.sort();
+        assert_eq!(first_shell, vec![1, 7, 8, 56, 64, 448]);
+
+        let mut data = vec![0.0f32; 1024];
+        fill_random(&mut data, 51);
+        let reordered = reorder_by_frequency(&data, n).unwrap();
+        assert_eq!(&reordered[0..2], &data[0..2]);
+        assert_eq!(restore_frequency_order(&reordered, n).unwrap(), data);
+
+        // 2048^3 = 2^33 indices cannot be represented as u32
+        let error = frequency_order_indices(2048).unwrap_err();
+        assert_eq!(error.code(), "INVALID_ARGUMENT");
+        assert_eq!(validate_spectrum_length(&data, usize::MAX), Err(volume_overflow(usize::MAX)));
+    }
+
+    #[test]
+    fn test_bispectrum_1d_detects_coupling() {
         let mut fft = FFT3D::new(8).unwrap();
-        let size = 512;
+        let n = 8;
 
-        // Create test data (real impulse at origin)
-        let mut input_real = vec![0.0f32; size];
-        let input_imag = vec![0.0f32; size];
-        input_real[0] = 1.0;
+        // Modes 1 and 2 along X: 1 + 1 = 2 is coupled, 1 + 2 = 3 is absent
+        let real: Vec<f32> = (0..512)
+            .map(|i| {
+                let x = (i % n) as f32 * 2.0 * std::f32::consts::PI / n as f32;
+                x.cos() + (2.0 * x).cos()
+            })
+            .collect();
+        let imag = vec![0.0f32; 512];
 
-        // Forward transform
-        let forward = fft.forward(&input_real, &input_imag).unwrap();
+        let spectrum = fft.forward(&real, &imag).unwrap();
+        let spec_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let spec_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
 
-        // Extract real and imaginary parts
-        let fwd_real: Vec<f32> = forward.iter().step_by(2).copied().collect();
-        let fwd_imag: Vec<f32> = forward.iter().skip(1).step_by(2).copied().collect();
+        let bispectrum = bispectrum_1d(&spec_real, &spec_imag, n).unwrap();
+        let bins = n / 2 + 1;
+        assert_eq!(bispectrum.len(), bins * bins * bins);
+        let at = |r1: usize, r2: usize, r3: usize| bispectrum[(r1 * bins + r2) * bins + r3];
+        let coupled = at(1, 1, 2);
+        assert!(coupled > 1.0);
+        assert!(at(1, 2, 3) < 1e-5 * coupled);
+        // -1 + 2 = 1 closes a triangle back onto the unit shell
+        assert!(at(1, 2, 1) > 1.0);
+        assert_eq!(at(1, 2, 1), at(2, 1, 1));
+        // No pair of unit-shell vectors sums to a wavevector in shell 4
+        assert_eq!(at(1, 1, 4), 0.0);
+    }
 
-        // Inverse transform
-        let inverse = fft.inverse(&fwd_real, &fwd_imag).unwrap();
+    #[test]
+    fn test_bispectrum_1d_rejects_invalid_size() {
+        let error = shell_bispectrum(&[], &[], 0).unwrap_err();
+        assert_eq!(error, FftError::UnsupportedSize { size: 0 });
+        let data = vec![0.0f32; 64];
+        assert_eq!(
+            shell_bispectrum(&data, &data[..63], 4),
+            Err(FftError::LengthMismatch { expected: 64, got: 63 })
+        );
+    }
+
+    #[test]
+    fn test_spectrum_hash() {
+        // FNV-1a offset basis for empty input
+        assert_eq!(spectrum_hash(&[]), "cbf29ce484222325");
+
+        let mut data = vec![0.0f32; 1024];
+        fill_random(&mut data, 47);
+        let hash = spectrum_hash(&data);
+        assert_eq!(hash.len(), 16);
+        assert_eq!(spectrum_hash(&data), hash);
+
+        data[1023] = -data[1023];
+        assert_ne!(spectrum_hash(&data), hash);
+        assert_ne!(spectrum_hash(&[0.0]), spectrum_hash(&[-0.0]));
+    }
+
+    #[test]
+    fn test_spectrum_base64_roundtrip() {
+        let mut data = vec![0.0f32; 1024];
+        fill_random(&mut data, 11);
+        data[0] = f32::MAX;
+        data[1] = -0.0;
+
+        let encoded = spectrum_to_base64(&data);
+        assert!(encoded.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+
+        let decoded = spectrum_from_base64(&encoded).unwrap();
+        let bits = |v: &[f32]| v.iter().map(|x| x.to_bits()).collect::<Vec<u32>>();
+        assert_eq!(bits(&decoded), bits(&data));
+
+        // Padding is accepted
+        let padded = format!("{}{}", encoded, "=".repeat((4 - encoded.len() % 4) % 4));
+        assert_eq!(bits(&spectrum_from_base64(&padded).unwrap()), bits(&data));
+    }
+
+    #[test]
+    fn test_size_info() {
+        let info = size_info(16).unwrap();
+        assert_eq!(info[0], 4096.0);
+        assert_eq!(info[1], 32768.0);
+        assert!(info[2] > 0.0);
+        assert!(size_info(32).unwrap()[2] > info[2]);
+    }
+
+    #[test]
+    fn test_lowpass_and_highpass() {
+        let fft = FFT3D::new(8).unwrap();
+        // Radii along X: k = 1 -> 0.25, k = 2 -> 0.5, k = 4 -> 1.0
+        let (mut low_re, mut low_im) = (vec![1.0f32; 512], vec![1.0f32; 512]);
+        fft.apply_lowpass(&mut low_re, &mut low_im, 0.5).unwrap();
+        assert_eq!((low_re[2], low_im[2]), (1.0, 1.0));
+        assert_eq!((low_re[3], low_im[3]), (0.0, 0.0));
+        assert_eq!(low_re[4], 0.0);
+
+        let (mut high_re, mut high_im) = (vec![1.0f32; 512], vec![1.0f32; 512]);
+        fft.apply_highpass(&mut high_re, &mut high_im, 0.5).unwrap();
+        assert_eq!(high_re[0], 0.0);
+        assert_eq!(high_re[1], 0.0);
+        assert_eq!(high_re[3], 1.0);
+
+        // Low and high pass at the same cutoff are complementary except at the edge
+        for i in 0..512 {
+            let expected = if fft.normalized_radius(i) == 0.5 { 2.0 } else { 1.0 };
+            assert_eq!(low_re[i] + high_re[i], expected);
+        }
+
+        // Smooth edge: gain 1/2 at the cutoff, between 0 and 1 inside the band
+        let (mut re, mut im) = (vec![1.0f32; 512], vec![0.0f32; 512]);
+        fft.apply_lowpass_smooth(&mut re, &mut im, 0.5, 0.6, Rolloff::Smoothstep).unwrap();
+        assert_eq!(re[0], 1.0);
+        assert!((re[2] - 0.5).abs() < 1e-6);
+        assert!(re[1] > 0.5 && re[1] < 1.0);
+        assert!(re[3] > 0.0 && re[3] < 0.5);
+        assert_eq!(re[4], 0.0);
+    }
+
+    #[test]
+    fn test_bandpass_and_notch() {
+        let fft = FFT3D::new(8).unwrap();
+        // Radii along X: k = 1 -> 0.25, k = 2 -> 0.5, k = 3 -> 0.75
+        let (mut band_re, mut band_im) = (vec![1.0f32; 512], vec![1.0f32; 512]);
+        fft.apply_bandpass(&mut band_re, &mut band_im, 0.25, 0.5).unwrap();
+        assert_eq!(band_re[0], 0.0);
+        assert_eq!((band_re[1], band_im[1]), (1.0, 1.0));
+        assert_eq!(band_re[2], 1.0);
+        assert_eq!((band_re[3], band_im[3]), (0.0, 0.0));
+
+        let (mut notch_re, mut notch_im) = (vec![1.0f32; 512], vec![1.0f32; 512]);
+        fft.apply_notch(&mut notch_re, &mut notch_im, 0.25, 0.5).unwrap();
+        for i in 0..512 {
+            assert_eq!(band_re[i] + notch_re[i], 1.0);
+            assert_eq!(band_im[i] + notch_im[i], 1.0);
+        }
+
+        // A degenerate band keeps exactly one shell
+        let (mut re, mut im) = (vec![1.0f32; 512], vec![0.0f32; 512]);
+        fft.apply_bandpass(&mut re, &mut im, 0.5, 0.5).unwrap();
+        for (i, &v) in re.iter().enumerate() {
+            assert_eq!(v, if fft.normalized_radius(i) == 0.5 { 1.0 } else { 0.0 });
+        }
+    }
+
+    #[test]
+    fn test_filters_on_external_spectrum() {
+        // A freshly created transformer has never run `forward`; the filters
+        // only see the spectrum passed in
+        let fft = FFT3D::new(8).unwrap();
+        let n = 8;
+        let index = |x: usize, y: usize, z: usize| (z * n + y) * n + x;
+
+        // Synthetic spectrum with known bins: DC, k = (1,0,0) at radius 0.25,
+        // its mirror (7,0,0), k = (0,2,2) at radius ~0.71 and k = (3,0,0) at 0.75
+        let bins = [index(0, 0, 0), index(1, 0, 0), index(7, 0, 0), index(0, 2, 2), index(3, 0, 0)];
+        let (mut re, mut im) = (vec![0.0f32; 512], vec![0.0f32; 512]);
+        for (j, &i) in bins.iter().enumerate() {
+            re[i] = j as f32 + 1.0;
+            im[i] = -(j as f32);
+        }
+
+        let (mut low_re, mut low_im) = (re.clone(), im.clone());
+        fft.apply_lowpass(&mut low_re, &mut low_im, 0.5).unwrap();
+        let survivors: Vec<usize> = (0..512).filter(|&i| low_re[i] != 0.0 || low_im[i] != 0.0).collect();
+        assert_eq!(survivors, vec![index(0, 0, 0), index(1, 0, 0), index(7, 0, 0)]);
+        assert_eq!((low_re[index(7, 0, 0)], low_im[index(7, 0, 0)]), (3.0, -2.0));
+
+        fft.apply_highpass(&mut re, &mut im, 0.5).unwrap();
+        let survivors: Vec<usize> = (0..512).filter(|&i| re[i] != 0.0 || im[i] != 0.0).collect();
+        assert_eq!(survivors, vec![index(3, 0, 0), index(0, 2, 2)]);
+        assert_eq!((re[index(0, 2, 2)], im[index(0, 2, 2)]), (4.0, -3.0));
+    }
+
+    #[test]
+    fn test_filter_rolloff() {
+        let fft = FFT3D::new(8).unwrap();
+        // Radii along X: k = 1 -> 0.25, k = 2 -> 0.5, k = 3 -> 0.75
+        let (mut low_re, mut low_im) = (vec![1.0f32; 512], vec![0.0f32; 512]);
+        fft.apply_lowpass_smooth(&mut low_re, &mut low_im, 0.5, 1.0, Rolloff::RaisedCosine).unwrap();
+        assert_eq!(low_re[0], 1.0);
+        assert!((low_re[1] - (0.5 + 0.5 * std::f32::consts::FRAC_1_SQRT_2)).abs() < 1e-6);
+        assert!((low_re[2] - 0.5).abs() < 1e-6);
+        assert_eq!(low_re[4], 0.0);
+
+        // Raised-cosine low- and high-pass at one cutoff sum to exactly 1
+        let (mut high_re, mut high_im) = (vec![1.0f32; 512], vec![0.0f32; 512]);
+        fft.apply_highpass_smooth(&mut high_re, &mut high_im, 0.5, 1.0, Rolloff::RaisedCosine).unwrap();
+        for (l, h) in low_re.iter().zip(high_re.iter()) {
+            assert!((l + h - 1.0).abs() < 1e-6);
+        }
+
+        // Band-pass edges: half gain at both edges, full gain between them
+        let (mut re, mut im) = (vec![1.0f32; 512], vec![0.0f32; 512]);
+        fft.apply_bandpass_smooth(&mut re, &mut im, 0.25, 0.75, 0.2, Rolloff::RaisedCosine).unwrap();
+        assert_eq!(re[0], 0.0);
+        assert!((re[1] - 0.5).abs() < 1e-6);
+        assert_eq!(re[2], 1.0);
+        assert!((re[3] - 0.5).abs() < 1e-6);
+
+        assert!(validate_transition(0.5, 0.5, 1.0).is_ok());
+        assert!(validate_transition(0.5, 0.5, 1.1).is_err());
+        assert!(validate_transition(0.1, 0.9, 0.3).is_err());
+        assert_eq!(validate_transition(0.5, 0.5, -0.1).unwrap_err().code(), "INVALID_ARGUMENT");
+        assert!(validate_transition(1.0, 1.0, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_apply_band_gains() {
+        let fft = FFT3D::new(8).unwrap();
+        let mut data = vec![1.0f32; 1024];
+
+        // Band 0 covers DC only; band 1 covers the first shell (radius 1/4 = 0.25)
+        let edges = [0.0, 0.1, 0.3];
+        fft.apply_band_gains(&mut data, &edges, &[2.0, 0.0], &[0.0, 1.0]).unwrap();
+
+        // DC: (1 + i) * 2
+        assert_eq!(&data[0..2], &[2.0, 2.0]);
+        // k = (1, 0, 0): (1 + i) * i = -1 + i
+        assert_eq!(&data[2..4], &[-1.0, 1.0]);
+        // k = (2, 0, 0) is outside every band
+        assert_eq!(&data[4..6], &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_apply_zerophase() {
+        let fft = FFT3D::new(8).unwrap();
+        let mut data: Vec<f32> = (0..1024).map(|i| (i % 11) as f32 - 5.0).collect();
+        let original = data.clone();
+        let mask: Vec<f32> = (0..512).map(|i| if i % 2 == 0 { 0.5 } else { -2.0 }).collect();
+
+        fft.apply_zerophase(&mut data, &mask).unwrap();
+        for i in 0..512 {
+            let gain = mask[i] * mask[i];
+            assert_eq!(data[2 * i], original[2 * i] * gain);
+            assert_eq!(data[2 * i + 1], original[2 * i + 1] * gain);
+            // Phase is unchanged
+            let before = original[2 * i + 1].atan2(original[2 * i]);
+            let after = data[2 * i + 1].atan2(data[2 * i]);
+            assert!((before - after).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_fftshift_roundtrip() {
+        let fft = FFT3D::new_dims(8, 4, 2).unwrap();
+        let mut data = vec![0.0f32; 128];
+        fill_random(&mut data, 131);
+        data[0] = 100.0;
+
+        let shifted = fft.fftshift(&data).unwrap();
+        // DC moves to (4, 2, 1)
+        let center = 2 * ((4 + 2) * 8 + 4);
+        assert_eq!(shifted[center], 100.0);
+        assert_eq!(fft.ifftshift(&shifted).unwrap(), data);
+
+        // Odd lengths split floor/ceil correctly
+        let odd = FFT3D { dims: [3, 1, 1], total_size: 3, ..FFT3D::new(2).unwrap() };
+        let line = [0.0, 0.0, 1.0, 1.0, 2.0, 2.0];
+        let shifted = odd.roll(&line, [1, 0, 0]);
+        assert_eq!(shifted, vec![2.0, 2.0, 0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(odd.ifftshift(&odd.fftshift(&line).unwrap()).unwrap(), line);
+    }
+
+    #[test]
+    fn test_rotate_global_phase() {
+        let fft = FFT3D::new(2).unwrap();
+        let mut data = vec![0.0f32; 16];
+        data[..4].copy_from_slice(&[1.0, 0.0, 0.0, 2.0]);
+
+        fft.rotate_global_phase(&mut data, std::f32::consts::FRAC_PI_2).unwrap();
+        let expected = [0.0, 1.0, -2.0, 0.0];
+        for (a, b) in data.iter().zip(expected.iter().chain([0.0; 12].iter())) {
+            assert!((a - b).abs() < 1e-6);
+        }
+
+        // Odd or wrong-size buffers are rejected rather than partially rotated
+        assert!(fft.validate_interleaved(&data[..15]).is_err());
+        assert!(fft.validate_interleaved(&data[..4]).is_err());
+    }
+
+    #[test]
+    fn test_worst_roundtrip_error() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut real = vec![0.0f32; 512];
+        let mut imag = vec![0.0f32; 512];
+        fill_random(&mut real, 5);
+        fill_random(&mut imag, 6);
+
+        let worst = fft.worst_roundtrip_error(&real, &imag).unwrap();
+        assert_eq!(worst.len(), 2);
+        assert!(worst[0] >= 0.0 && worst[0] < 512.0);
+        assert!(worst[1] < 1e-5);
+    }
+
+    #[test]
+    fn test_monogenic_signal_of_plane_wave() {
+        let n = 16;
+        let mut fft = FFT3D::new(n).unwrap();
+        let theta = |i: usize| 2.0 * std::f32::consts::PI * (i % n) as f32 / n as f32;
+        let field: Vec<f32> = (0..4096).map(|i| theta(i).cos()).collect();
+
+        let monogenic = fft.monogenic_signal(&field).unwrap();
+        assert_eq!(monogenic.len(), 4 * 4096);
+        assert_eq!(&monogenic[..4096], &field[..]);
+
+        // A cosine along X has Riesz X component -sin and unit local amplitude
+        for i in 0..4096 {
+            assert!((monogenic[4096 + i] + theta(i).sin()).abs() < 1e-4);
+            assert!(monogenic[2 * 4096 + i].abs() < 1e-4);
+            assert!(monogenic[3 * 4096 + i].abs() < 1e-4);
+            let amplitude = (field[i].powi(2) + monogenic[4096 + i].powi(2)).sqrt();
+            assert!((amplitude - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_correlation_length_grows_with_smoothing() {
+        let mut fft = FFT3D::new(16).unwrap();
+        let mut noise = vec![0.0f32; 4096];
+        fill_random(&mut noise, 9);
+
+        // White noise decorrelates within a voxel
+        let white = fft.correlation_length(&noise).unwrap();
+        assert!(white < 1.0, "White noise length {}", white);
+
+        // Box-smoothing along every axis lengthens the correlation
+        let n = 16;
+        let mut smooth = vec![0.0f32; 4096];
+        for z in 0..n {
+            for y in 0..n {
+                for x in 0..n {
+                    let mut sum = 0.0;
+                    for d in 0..4 {
+                        sum += noise[z * n * n + y * n + (x + d) % n];
+                        sum += noise[z * n * n + ((y + d) % n) * n + x];
+                        sum += noise[((z + d) % n) * n * n + y * n + x];
+                    }
+                    smooth[z * n * n + y * n + x] = sum;
+                }
+            }
+        }
+        let smoothed = fft.correlation_length(&smooth).unwrap();
+        assert!(smoothed > white, "Smoothed length {} <= white {}", smoothed, white);
+    }
+
+    #[test]
+    fn test_forward_descriptors() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let n = 8;
 
-        // Extract result
-        let inv_real: Vec<f32> = inverse.iter().step_by(2).copied().collect();
+        // Wave with period 4 voxels along X
+        let real: Vec<f32> = (0..512)
+            .map(|i| (2.0 * std::f32::consts::PI * (i % n) as f32 / 4.0).cos())
+            .collect();
+        let imag = vec![0.0f32; 512];
+
+        let d = fft.forward_descriptors(&real, &imag).unwrap();
+        assert_eq!(d.len(), 6);
+
+        let spectrum = fft.forward(&real, &imag).unwrap();
+        let spec_real: Vec<f32> = spectrum.iter().step_by(2).copied().collect();
+        let spec_imag: Vec<f32> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        let energy = calculate_energy(&spec_real, &spec_imag).unwrap();
+        assert!((d[0] - energy).abs() < 1e-2 * energy);
+
+        // All energy sits at |f| = 0.25 in two modes, so the spectrum is not flat
+        assert!((d[1] - 0.25).abs() < 1e-4);
+        assert!(d[2] < 1e-3);
+        assert_eq!(d[3], 0.0);
+        assert!((d[4] - 4.0).abs() < 1e-4);
+        assert!((d[5] - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_best_fit_size_and_forward_auto() {
+        assert_eq!(best_fit_size(1), Some(2));
+        assert_eq!(best_fit_size(512), Some(8));
+        assert_eq!(best_fit_size(513), Some(16));
+        assert_eq!(best_fit_size(32769), Some(64));
+        assert_eq!(best_fit_size(256 * 256 * 256 + 1), None);
+
+        let data = vec![1.0f32; 600];
+        let output = forward_auto(&data).unwrap();
+        assert_eq!(output.len(), 2 * 4096);
+        // DC term is the sum of the data; padding contributes nothing
+        assert!((output[0] - 600.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_try_alloc() {
+        let buffer = try_alloc(16, 1.5f32, 8).unwrap();
+        assert_eq!(buffer, vec![1.5f32; 16]);
+    }
+
+    #[test]
+    fn test_try_alloc_out_of_memory() {
+        let error = try_alloc(usize::MAX / 2, 0.0f32, 256).unwrap_err();
+        assert_eq!(error, FftError::OutOfMemory { size: 256 });
+        assert_eq!(error.code(), "OUT_OF_MEMORY");
+    }
+
+    #[test]
+    fn test_fft_error_codes() {
+        let fft = FFT3D::new(4).unwrap();
+        let error = fft.validate_interleaved(&[0.0; 100]).unwrap_err();
+        assert_eq!(error, FftError::LengthMismatch { expected: 128, got: 100 });
+
+        let error = validate_pair_length(&[0.0; 4], &[0.0; 3], 4).unwrap_err();
+        assert_eq!(error, FftError::LengthMismatch { expected: 4, got: 3 });
+        assert_eq!(validate_even_length(&[0.0; 3]).unwrap_err().code(), "INVALID_LENGTH");
+        assert_eq!(validate_axis(3).unwrap_err().code(), "INVALID_ARGUMENT");
+        assert_eq!(
+            validate_axis(3).unwrap_err().to_string(),
+            "Invalid axis: 3. Expected 0 (X), 1 (Y) or 2 (Z)"
+        );
+
+        let error = FFT3D::new_dims(4, 4, 8).unwrap().require_cubic().unwrap_err();
+        assert_eq!(error.code(), "NON_CUBIC");
+        assert_eq!(error.to_string(), "Operation requires a cubic transform, got 4x4x8");
+
+        let error = FftError::RegionOutOfBounds {
+            origin: [0, 2, 1],
+            size: 4,
+            parent_size: 5,
+        };
+        assert_eq!(error.code(), "REGION_OUT_OF_BOUNDS");
+        assert_eq!(error.to_string(), "Region at (0, 2, 1) of size 4 exceeds parent size 5");
+    }
+
+    #[test]
+    fn test_cube_volume_overflow() {
+        assert_eq!(cube_volume(16), Ok(4096));
+        let huge = 1usize << (usize::BITS / 2);
+        assert_eq!(cube_volume(huge).unwrap_err().code(), "INVALID_ARGUMENT");
+        assert_eq!(cube_volume(usize::MAX), Err(volume_overflow(usize::MAX)));
+    }
+
+    #[test]
+    fn test_validate_region() {
+        assert!(validate_region([0, 4, 8], 8, 16).is_ok());
+        assert!(validate_region([9, 0, 0], 8, 16).is_err());
+        // Larger than the parent, and an origin that would wrap `o + size`
+        assert!(validate_region([0, 0, 0], 32, 16).is_err());
+        let error = validate_region([usize::MAX, 0, 0], 8, 16).unwrap_err();
+        assert_eq!(error.code(), "REGION_OUT_OF_BOUNDS");
+    }
+
+    #[test]
+    fn test_fft_error_variants() {
+        assert_eq!(validate_size(48), Err(FftError::UnsupportedSize { size: 48 }));
+        assert_eq!(
+            validate_size(5).unwrap_err().to_string(),
+            "Unsupported FFT size: 5. Supported sizes: powers of two from 2 to 256"
+        );
+        assert!(FFTPlanCache::new().get_plans(12).is_err());
+
+        let fft = FFT3D::new(8).unwrap();
+        let error = fft.validate_input(&[0.0; 512], &[0.0; 100]).unwrap_err();
+        assert_eq!(error, FftError::LengthMismatch { expected: 512, got: 100 });
+        assert_eq!(error.code(), "LENGTH_MISMATCH");
+        assert_eq!(error.to_string(), "Invalid input length: expected 512, got 100");
+        assert_eq!(FftError::EmptyInput.code(), "EMPTY_INPUT");
+    }
+
+    /// Reference 3D FFT that gathers and scatters strided columns one at a time
+    fn strided_fft_3d(buffer: &mut [Complex<f32>], n: usize, fft: &Arc<dyn Fft<f32>>) {
+        let mut scratch = vec![Complex::new(0.0f32, 0.0f32); fft.get_inplace_scratch_len()];
+        for row in buffer.chunks_exact_mut(n) {
+            fft.process_with_scratch(row, &mut scratch);
+        }
+        for stride in [n, n * n] {
+            for start in 0..n * n * n {
+                if (start / stride) % n != 0 {
+                    continue;
+                }
+                let mut column: Vec<Complex<f32>> = (0..n).map(|k| buffer[start + k * stride]).collect();
+                fft.process_with_scratch(&mut column, &mut scratch);
+                for (k, c) in column.iter().enumerate() {
+                    buffer[start + k * stride] = *c;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_allocations_per_transform_constant() {
+        let transform_allocations = |n: usize| {
+            let mut fft = FFT3D::new(n).unwrap();
+            let mut data = vec![0.0f32; 2 * n * n * n];
+            fill_random(&mut data, 5);
+            // Warm the plan cache and the work buffer
+            fft.forward_in_place(&mut data).unwrap();
+            count_allocations(|| fft.forward_in_place(&mut data).unwrap())
+        };
+
+        // Scratch and transpose buffers are allocated once per transform,
+        // not once per column, so the count does not grow with the size
+        let at_32 = transform_allocations(32);
+        assert_eq!(at_32, transform_allocations(8));
+        assert!(at_32 <= 4, "{} allocations per 32^3 transform", at_32);
+
+        // The per-column gather allocates 2 * n^2 column vectors
+        let n = 32;
+        let plan = FftPlanner::new().plan_fft_forward(n);
+        let mut buffer = vec![Complex::new(1.0f32, 0.0); n * n * n];
+        let strided = count_allocations(|| strided_fft_3d(&mut buffer, n, &plan));
+        assert!(strided >= 2 * n * n);
+    }
+
+    #[test]
+    fn test_transposed_fft_matches_strided() {
+        for n in [8, 16, 32] {
+            let total = n * n * n;
+            let mut real = vec![0.0f32; total];
+            let mut imag = vec![0.0f32; total];
+            fill_random(&mut real, 31);
+            fill_random(&mut imag, 32);
+
+            let mut fft = FFT3D::new(n).unwrap();
+            let output = fft.forward(&real, &imag).unwrap();
+
+            let mut reference: Vec<Complex<f32>> =
+                real.iter().zip(imag.iter()).map(|(&re, &im)| Complex::new(re, im)).collect();
+            let plan = FftPlanner::new().plan_fft_forward(n);
+            strided_fft_3d(&mut reference, n, &plan);
+
+            for (i, c) in reference.iter().enumerate() {
+                assert_eq!(output[2 * i], c.re, "Real mismatch at {} for size {}", i, n);
+                assert_eq!(output[2 * i + 1], c.im, "Imag mismatch at {} for size {}", i, n);
+            }
+        }
+    }
+
+    #[test]
+    #[ignore = "timing benchmark; run with --ignored --nocapture"]
+    fn bench_transposed_vs_strided() {
+        let n = 32;
+        let total = n * n * n;
+        let iterations = 50;
+        let mut real = vec![0.0f32; total];
+        let mut imag = vec![0.0f32; total];
+        fill_random(&mut real, 1);
+        fill_random(&mut imag, 2);
+        let input: Vec<Complex<f32>> =
+            real.iter().zip(imag.iter()).map(|(&re, &im)| Complex::new(re, im)).collect();
+
+        let mut fft = FFT3D::new(n).unwrap();
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            let mut buffer = input.clone();
+            fft.fft_3d(&mut buffer, true).unwrap();
+        }
+        let transposed = start.elapsed().as_secs_f64() * 1000.0 / iterations as f64;
+
+        let plan = FftPlanner::new().plan_fft_forward(n);
+        let start = std::time::Instant::now();
+        for _ in 0..iterations {
+            let mut buffer = input.clone();
+            strided_fft_3d(&mut buffer, n, &plan);
+        }
+        let strided = start.elapsed().as_secs_f64() * 1000.0 / iterations as f64;
+
+        println!("{}^3 forward: transposed {:.3} ms, strided {:.3} ms", n, transposed, strided);
+    }
+
+    #[test]
+    fn test_simd_flags() {
+        assert_eq!(simd_enabled(), cfg!(target_feature = "simd128"));
+        #[cfg(not(target_arch = "wasm32"))]
+        assert!(!simd_runtime_supported());
+    }
+
+    #[test]
+    fn test_matched_filter_locates_template() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let n = 8;
+        let mut template = vec![0.0f32; 512];
+        template[0] = 1.0;
+        template[1] = 0.5;
+        template[n] = -0.5;
+
+        // Signal holds 2x the template shifted by (3, 1, 2)
+        let offset = 2 * n * n + n + 3;
+        let mut signal = vec![0.0f32; 512];
+        for (i, &t) in template.iter().enumerate() {
+            let (x, y, z) = (i % n, (i / n) % n, i / (n * n));
+            let shifted = ((z + 2) % n) * n * n + ((y + 1) % n) * n + (x + 3) % n;
+            signal[shifted] += 2.0 * t;
+        }
+
+        let response = fft.matched_filter(&signal, &template).unwrap();
+
+        // The response does not depend on the normalization convention
+        for mode in [NormMode::Forward, NormMode::Ortho, NormMode::OrthonormalPerAxis] {
+            fft.set_norm(mode);
+            let scaled = fft.matched_filter(&signal, &template).unwrap();
+            for (a, b) in scaled.iter().zip(response.iter()) {
+                assert!((a - b).abs() < 1e-5, "Response mismatch for {:?}", mode);
+            }
+        }
+
+        let (peak_index, peak) = response
+            .iter()
+            .enumerate()
+            .fold((0, f32::MIN), |best, (i, &v)| if v > best.1 { (i, v) } else { best });
+        assert_eq!(peak_index, offset);
+        assert!((peak - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_phase_correlation_finds_shift() {
+        let n = 8;
+        let mut fft = FFT3D::new(n).unwrap();
+        let mut b = vec![0.0f32; 512];
+        fill_random(&mut b, 141);
+        let zeros = vec![0.0f32; 512];
+
+        // a is b shifted by (3, -1, 2) with wrap-around
+        let mut a = vec![0.0f32; 512];
+        for (i, &v) in b.iter().enumerate() {
+            let (x, y, z) = (i % n, (i / n) % n, i / (n * n));
+            a[((z + 2) % n) * n * n + ((y + n - 1) % n) * n + (x + 3) % n] = v;
+        }
+
+        for mode in [NormMode::Backward, NormMode::Forward] {
+            fft.set_norm(mode);
+            let surface = fft.phase_correlation(&a, &zeros, &b, &zeros).unwrap();
+            assert_eq!(fft.find_peak_offset(&surface).unwrap(), vec![3, -1, 2]);
+            let peak = 2 * ((2 * n + n - 1) * n + 3);
+            assert!((surface[peak] - 1.0).abs() < 1e-4, "Peak {} for {:?}", surface[peak], mode);
+        }
+    }
+
+    #[test]
+    fn test_fill_random_deterministic() {
+        let mut a = vec![0.0f32; 256];
+        let mut b = vec![0.0f32; 256];
+        let mut c = vec![0.0f32; 256];
+        fill_random(&mut a, 42);
+        fill_random(&mut b, 42);
+        fill_random(&mut c, 43);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a.iter().all(|&v| (-1.0..1.0).contains(&v)));
+    }
 
-        // Check roundtrip (should recover original impulse)
-        assert!((inv_real[0] - 1.0).abs() < 1e-5, "Expected ~1.0, got {}", inv_real[0]);
+    #[test]
+    fn test_chunked_input_matches_forward() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let real: Vec<f32> = (0..512).map(|i| (i % 9) as f32).collect();
+        let imag = vec![0.0f32; 512];
 
-        // Other values should be near zero
-        for i in 1..size {
-            assert!(inv_real[i].abs() < 1e-5, "Expected ~0.0 at {}, got {}", i, inv_real[i]);
+        let mut chunked = ChunkedInput::new(8).unwrap();
+        for chunk in real.chunks(100) {
+            assert!(!chunked.complete());
+            chunked.push_chunk(chunk).unwrap();
         }
+        assert!(chunked.complete());
+        assert_eq!(chunked.collected(), 512);
+
+        let expected = fft.forward(&real, &imag).unwrap();
+        assert_eq!(chunked.transform().unwrap(), expected);
+
+        chunked.reset();
+        assert_eq!(chunked.collected(), 0);
+    }
+
+    #[test]
+    fn test_spatial_histogram() {
+        // Real parts 0..512, imaginary parts ignored
+        let data: Vec<f32> = (0..512).flat_map(|i| [i as f32, 1000.0]).collect();
+
+        let histogram = spatial_histogram(&data, 8, 4).unwrap();
+        assert_eq!(histogram.min(), 0.0);
+        assert_eq!(histogram.max(), 511.0);
+
+        let counts = histogram.counts();
+        assert_eq!(counts.iter().sum::<f32>(), 512.0);
+        assert_eq!(counts, vec![128.0, 128.0, 128.0, 128.0]);
+    }
+
+    #[test]
+    fn test_field_stats() {
+        // Real parts alternate 1 and -3, imaginary parts are ignored
+        let data: Vec<f32> = (0..512).flat_map(|i| [if i % 2 == 0 { 1.0 } else { -3.0 }, 100.0]).collect();
+
+        let stats = field_stats(&data, 8).unwrap();
+        assert!((stats[0] - 5.0f32.sqrt()).abs() < 1e-6);
+        assert_eq!(stats[1], 3.0);
+        assert_eq!(stats[2], -1.0);
     }
 
     #[test]
@@ -376,4 +7676,108 @@ mod tests {
         // 3^2 + 4^2 + 0^2 + 5^2 = 9 + 16 + 0 + 25 = 50
         assert!((energy - 50.0).abs() < 1e-5);
     }
+
+    #[test]
+    fn test_magnitude_and_phase() {
+        let data = [3.0, 4.0, -1.0, 0.0, 0.0, -2.0];
+        assert_eq!(magnitude(&data).unwrap(), vec![5.0, 1.0, 2.0]);
+
+        let phases = phase(&data).unwrap();
+        assert!((phases[0] - 4.0f32.atan2(3.0)).abs() < 1e-6);
+        assert!((phases[1] - std::f32::consts::PI).abs() < 1e-6);
+        assert!((phases[2] + std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_power_spectrum() {
+        let real = vec![3.0, 0.0, 0.0];
+        let imag = vec![4.0, 10.0, 0.0];
+
+        let power = power_spectrum(&real, &imag).unwrap();
+        assert_eq!(power, vec![25.0, 100.0, 0.0]);
+        assert!((power.iter().sum::<f32>() - calculate_energy(&real, &imag).unwrap()).abs() < 1e-5);
+
+        let db = log_power_spectrum(&real, &imag, 1e-12).unwrap();
+        assert!((db[1] - 20.0).abs() < 1e-4);
+        assert!((db[2] + 120.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_radial_spectrum_of_symmetric_input() {
+        let n = 16;
+        // Amplitude r + 1 on every shell of rounded radius r
+        let shell = |i: usize| {
+            let f = |k: usize| signed_frequency(k, n);
+            let (fx, fy, fz) = (f(i % n), f((i / n) % n), f(i / (n * n)));
+            (fx * fx + fy * fy + fz * fz).sqrt().round()
+        };
+        let real: Vec<f32> = (0..4096).map(|i| shell(i) + 1.0).collect();
+        let imag = vec![0.0f32; 4096];
+
+        let profile = radial_spectrum(&real, &imag).unwrap();
+        assert_eq!(profile.len(), n / 2 + 1);
+        for (r, &p) in profile.iter().enumerate() {
+            let expected = ((r + 1) * (r + 1)) as f32;
+            assert!((p - expected).abs() < 1e-3, "Radius {}: {} vs {}", r, p, expected);
+        }
+    }
+
+    #[test]
+    fn test_spectral_energy_difference_matches_spatial() {
+        let mut fft = FFT3D::new(8).unwrap();
+        let mut a = vec![0.0f32; 512];
+        let mut b = vec![0.0f32; 512];
+        fill_random(&mut a, 71);
+        fill_random(&mut b, 72);
+        let zeros = vec![0.0f32; 512];
+
+        let spectrum_a = fft.forward(&a, &zeros).unwrap();
+        let spectrum_b = fft.forward(&b, &zeros).unwrap();
+        let spectral = spectral_energy_difference(&spectrum_a, &spectrum_b).unwrap();
+
+        // Parseval: N times the spatial squared distance
+        let spatial: f32 = a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum();
+        assert!((spectral - 512.0 * spatial).abs() < 1e-3 * spectral);
+        assert_eq!(spectral_energy_difference(&spectrum_a, &spectrum_a).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_golden_forward_snapshot() {
+        // Pinned output; a change here means the numerics drifted (e.g. rustfft upgrade)
+        let expected = [
+            26.601269, 10.306874, -10.979366, 22.965664,
+            -2.2360835, -13.17767, -0.45299911, 11.580137,
+        ];
+        let output = golden_forward(8, 2024);
+        for (i, (&a, &b)) in output.iter().zip(expected.iter()).enumerate() {
+            assert!((a - b).abs() < 1e-3, "Drift at {}: expected {}, got {}", i, b, a);
+        }
+    }
+
+    #[test]
+    fn test_golden_forward_matches_reference_dft() {
+        let n = 8;
+        let total = n * n * n;
+        let mut real = vec![0.0f32; total];
+        let mut imag = vec![0.0f32; total];
+        fill_random(&mut real, 2024);
+        fill_random(&mut imag, 2025);
+        let output = golden_forward(n, 2024);
+
+        // Naive O(N^2) 3D DFT in f64 as the reference
+        let w = -2.0 * std::f64::consts::PI / n as f64;
+        for k in 0..total {
+            let (kx, ky, kz) = (k % n, (k / n) % n, k / (n * n));
+            let (mut re, mut im) = (0.0f64, 0.0f64);
+            for j in 0..total {
+                let (x, y, z) = (j % n, (j / n) % n, j / (n * n));
+                let angle = w * ((kx * x + ky * y + kz * z) % n) as f64;
+                let (sin, cos) = angle.sin_cos();
+                re += real[j] as f64 * cos - imag[j] as f64 * sin;
+                im += real[j] as f64 * sin + imag[j] as f64 * cos;
+            }
+            assert!((output[2 * k] as f64 - re).abs() < 1e-3, "Real mismatch at {}", k);
+            assert!((output[2 * k + 1] as f64 - im).abs() < 1e-3, "Imag mismatch at {}", k);
+        }
+    }
 }